@@ -1,24 +1,104 @@
 import init, { GameState } from './pkg/mehen.js';
 
 let game = null;
-let boardSize = 600;
+let layout = null;
+// Die the player picked to move with (index into the pool), or null for the first that fits
+let selectedDie = null;
+// Piece waiting for the player to click one of several destinations: { seat, piece, moves }
+let pendingChoice = null;
 
 async function loadGame() {
     await init();
     game = new GameState();
+    game.set_locale(navigator.language || 'en');
+    applyLocale();
     updateGameState();
 }
 
 function updateGameState() {
     if (!game) return;
     
+    clearChoice();
     const board = document.getElementById('game-board');
-    boardSize = board ? board.offsetWidth : 600;
+    applyLayout();
     
     const statusDisplay = game.get_status_display();
     updateStatus(statusDisplay.message, statusDisplay.color);
     renderBoard();
     updateUI();
+    if (board) {
+        board.setAttribute('aria-label', game.get_position_description());
+    }
+    showFocus(game.get_focus());
+}
+
+// Size the board, squares and start area for the space left on the page
+function applyLayout() {
+    const board = document.getElementById('game-board');
+    const container = document.querySelector('.container');
+    if (!board || !container) return;
+    
+    const style = getComputedStyle(container);
+    const width = container.clientWidth - parseFloat(style.paddingLeft) - parseFloat(style.paddingRight);
+    const height = window.innerHeight - board.offsetTop - 60;
+    const orientation = screen.orientation ? screen.orientation.type : '';
+    layout = game.get_layout(width, Math.max(height, 0), window.devicePixelRatio || 1, orientation);
+    
+    board.style.width = `${layout.board_size}px`;
+    board.style.height = `${layout.board_size}px`;
+    const centerHead = board.querySelector('.center-head');
+    if (centerHead) {
+        centerHead.style.width = `${layout.head_width}px`;
+        centerHead.style.height = `${layout.head_height}px`;
+    }
+    document.querySelector('.play-area').classList.toggle('side', layout.placement === 'Side');
+    const status = document.getElementById('status');
+    if (status) {
+        status.style.fontSize = `${layout.fonts.status}px`;
+    }
+}
+
+// Read text out through the ARIA live region
+function announce(text) {
+    const announcer = document.getElementById('announcer');
+    if (announcer && text) {
+        announcer.textContent = text;
+    }
+}
+
+// Outline the piece selected from the keyboard
+function showFocus(focus) {
+    document.querySelectorAll('.keyboard-focus').forEach((el) => el.classList.remove('keyboard-focus'));
+    if (!focus) return;
+    const target = focus.from === 0
+        ? document.querySelector(`.${focus.player.toLowerCase()}-area .start-piece.valid-move`)
+        : document.querySelectorAll('.spiral-square')[focus.from - 1];
+    if (target) {
+        target.classList.add('keyboard-focus');
+    }
+}
+
+function handleBoardKey(event) {
+    if (!game || game.game_over) return;
+    if (!['ArrowRight', 'ArrowDown', 'ArrowLeft', 'ArrowUp', 'Enter', ' ', 'Escape'].includes(event.key)) return;
+    
+    event.preventDefault();
+    const result = game.handle_key(event.key);
+    if (result.moved) {
+        updateGameState();
+    } else {
+        showFocus(result.focus);
+    }
+    announce(result.announcement);
+}
+
+// Translate the page's fixed labels and set its language and text direction
+function applyLocale() {
+    document.documentElement.lang = game.get_locale();
+    document.documentElement.dir = game.get_text_direction();
+    document.querySelectorAll('[data-i18n]').forEach((el) => {
+        el.textContent = game.get_message(el.dataset.i18n);
+    });
 }
 
 function renderBoard() {
@@ -28,7 +108,8 @@ function renderBoard() {
     const existingSquares = board.querySelectorAll('.spiral-square');
     existingSquares.forEach(sq => sq.remove());
     
-    const squareSize = game.get_square_size(window.innerWidth);
+    const boardSize = layout.board_size;
+    const squareSize = layout.square_size;
     const spiralPositions = game.get_spiral_positions(boardSize);
     
     spiralPositions.forEach((pos, squareIndex) => {
@@ -38,8 +119,19 @@ function renderBoard() {
         square.className = renderInfo.classes;
         square.style.left = `${renderInfo.left}px`;
         square.style.top = `${renderInfo.top}px`;
+        square.style.width = `${squareSize}px`;
+        square.style.height = `${squareSize}px`;
+        square.style.fontSize = `${layout.fonts.piece}px`;
         square.innerHTML = renderInfo.inner_html;
+        if (renderInfo.background) {
+            square.style.background = renderInfo.background;
+            square.style.color = renderInfo.color;
+        }
         square.onclick = () => handleSquareClick(squareIndex);
+        if (renderInfo.preview) {
+            square.onmouseenter = () => showPreview(renderInfo.preview);
+            square.onmouseleave = clearPreview;
+        }
         
         board.appendChild(square);
     });
@@ -52,41 +144,112 @@ function renderBoard() {
             const piece = document.createElement('span');
             piece.className = p.class;
             piece.textContent = p.text;
+            piece.style.color = p.color;
             centerHead.appendChild(piece);
         });
     }
     
-    const startArea = document.querySelector('.start-area');
-    const startPieces = game.get_start_pieces_html();
-    if (startPieces.length > 0) {
-        if (!startArea) {
-            const area = document.createElement('div');
-            area.className = 'start-area';
-            const piecesDiv = document.createElement('div');
-            piecesDiv.className = 'start-pieces';
-            piecesDiv.innerHTML = '<strong>Add Piece:</strong>';
-            area.appendChild(piecesDiv);
-            document.querySelector('.container').insertBefore(area, document.getElementById('status'));
+    renderPlayerAreas();
+}
+
+// One area per player with their waiting and finished pieces; the area of
+// the player to move is highlighted
+function renderPlayerAreas() {
+    let startArea = document.querySelector('.start-area');
+    if (!startArea) {
+        startArea = document.createElement('div');
+        startArea.className = 'start-area';
+        document.querySelector('.play-area').appendChild(startArea);
+    }
+    startArea.innerHTML = '';
+    
+    const makePiece = (area, p) => {
+        const piece = document.createElement('span');
+        piece.className = p.class;
+        if (area.image) {
+            const img = document.createElement('img');
+            img.className = 'piece-image';
+            img.src = area.image;
+            img.alt = area.glyph;
+            piece.appendChild(img);
+        } else {
+            piece.textContent = area.glyph;
         }
+        piece.style.background = area.color;
+        piece.style.color = area.text_color;
+        piece.style.width = `${layout.start_piece_size}px`;
+        piece.style.height = `${layout.start_piece_size}px`;
+        piece.style.lineHeight = `${layout.start_piece_size}px`;
+        piece.style.fontSize = `${layout.fonts.start_piece}px`;
+        return piece;
+    };
+    
+    game.get_player_areas().forEach((area) => {
+        const areaDiv = document.createElement('div');
+        areaDiv.className = area.class;
+        areaDiv.style.minHeight = `${layout.bench_size}px`;
+        
+        const name = document.createElement('strong');
+        name.textContent = area.name;
+        areaDiv.appendChild(name);
         
-        const piecesDiv = document.querySelector('.start-pieces');
-        if (piecesDiv) {
-            const existingPieces = piecesDiv.querySelectorAll('.start-piece');
-            existingPieces.forEach(p => p.remove());
-            
-            startPieces.forEach((p) => {
-                const piece = document.createElement('span');
-                piece.className = p.class;
-                piece.textContent = p.text;
-                if (p.is_valid) {
-                    piece.onclick = () => handlePieceClick(p.index);
-                }
-                piecesDiv.appendChild(piece);
-            });
+        const waiting = document.createElement('div');
+        waiting.className = 'start-pieces';
+        waiting.title = area.waiting_label;
+        area.waiting.forEach((p) => {
+            const piece = makePiece(area, p);
+            if (area.title) {
+                piece.title = area.title;
+            }
+            if (p.is_valid) {
+                piece.onclick = () => handleAreaPieceClick(area.seat, p.index);
+            }
+            if (p.preview) {
+                piece.onmouseenter = () => showPreview(p.preview);
+                piece.onmouseleave = clearPreview;
+            }
+            waiting.appendChild(piece);
+        });
+        areaDiv.appendChild(waiting);
+        
+        const home = document.createElement('div');
+        home.className = 'home-pieces';
+        const homeLabel = document.createElement('span');
+        homeLabel.textContent = area.home_label;
+        home.appendChild(homeLabel);
+        area.home.forEach((p) => home.appendChild(makePiece(area, p)));
+        areaDiv.appendChild(home);
+        
+        startArea.appendChild(areaDiv);
+    });
+}
+
+// Highlight where a hovered piece would land and which pieces it would leave open
+function showPreview(preview) {
+    clearPreview();
+    const squares = document.querySelectorAll('.spiral-square');
+    const mark = (position, className, title) => {
+        const square = squares[Math.min(position, 36) - 1];
+        if (square) {
+            square.classList.add(className);
+            if (title) square.title = title;
         }
-    } else if (startArea) {
-        startArea.remove();
-    }
+    };
+    
+    const lead = preview.lead_change >= 0 ? `+${preview.lead_change}` : `${preview.lead_change}`;
+    const key = preview.captured ? 'preview.lead_capture' : 'preview.lead';
+    mark(preview.to, 'preview-destination', game.format_message(key, { lead }));
+    preview.exposed.forEach((e) => {
+        const percent = `${Math.round(e.chance * 100)}`;
+        mark(e.square, 'preview-exposed', game.format_message('preview.exposed', { percent }));
+    });
+}
+
+function clearPreview() {
+    document.querySelectorAll('.preview-destination, .preview-exposed').forEach((square) => {
+        square.classList.remove('preview-destination', 'preview-exposed');
+        square.removeAttribute('title');
+    });
 }
 
 function updateUI() {
@@ -102,7 +265,7 @@ function updateUI() {
     }
     
     if (diceValueEl) {
-        diceValueEl.textContent = game.get_dice_display();
+        renderDice(diceValueEl);
     }
     
     if (rollBtn) {
@@ -110,6 +273,72 @@ function updateUI() {
     }
 }
 
+// One button per die when there are several, so the player can choose which to use
+function renderDice(diceValueEl) {
+    const dice = game.get_dice();
+    if (selectedDie !== null && selectedDie >= dice.length) {
+        selectedDie = null;
+    }
+    diceValueEl.replaceChildren();
+    diceValueEl.classList.toggle('dice-pool', dice.length > 1);
+    if (dice.length <= 1) {
+        diceValueEl.textContent = game.get_dice_display();
+        return;
+    }
+    const usable = new Set(game.get_valid_moves().map((move) => move.die));
+    dice.forEach((die, index) => {
+        const button = document.createElement('button');
+        button.className = 'die';
+        button.textContent = die;
+        button.disabled = !usable.has(die);
+        button.setAttribute('aria-pressed', String(index === selectedDie));
+        button.setAttribute('aria-label', game.format_message('ui.use_die', { die: String(die) }));
+        button.addEventListener('click', () => {
+            selectedDie = selectedDie === index ? null : index;
+            updateUI();
+        });
+        diceValueEl.appendChild(button);
+    });
+}
+
+// Move a piece with the selected die. Without one, a piece that could go
+// different distances (other dice, or part of a split roll) asks where to go.
+function playPiece(seat, pieceIndex) {
+    let moved;
+    if (selectedDie === null) {
+        const moves = game.get_piece_moves(seat, pieceIndex);
+        if (moves.length > 1) {
+            offerChoice(seat, pieceIndex, moves);
+            return;
+        }
+        moved = game.make_move_piece(seat, pieceIndex);
+    } else {
+        moved = game.make_move_piece_with_die(seat, pieceIndex, selectedDie);
+    }
+    if (moved) {
+        selectedDie = null;
+        updateGameState();
+        announce(game.get_last_move_announcement());
+    }
+}
+
+// Mark each square the piece could reach; clicking one plays that move
+function offerChoice(seat, pieceIndex, moves) {
+    clearChoice();
+    pendingChoice = { seat, piece: pieceIndex, moves };
+    const squares = document.querySelectorAll('.spiral-square');
+    moves.forEach((move) => {
+        const square = squares[Math.min(move.to, 36) - 1];
+        if (square) square.classList.add('step-choice');
+    });
+    announce(game.get_message('status.choose_destination'));
+}
+
+function clearChoice() {
+    pendingChoice = null;
+    document.querySelectorAll('.step-choice').forEach((square) => square.classList.remove('step-choice'));
+}
+
 function updateStatus(message, color) {
     const statusEl = document.getElementById('status');
     if (statusEl) {
@@ -121,8 +350,10 @@ function updateStatus(message, color) {
 function handleRollDice() {
     if (!game || game.game_over) return;
     
+    selectedDie = null;
     const rolledValue = game.roll_dice();
     updateGameState();
+    announce(game.get_position_description());
     
     if (game.should_auto_pass_turn(rolledValue)) {
         setTimeout(() => {
@@ -136,6 +367,7 @@ function handleReset() {
     if (!game) return;
     
     game.reset();
+    selectedDie = null;
     const statusDisplay = game.get_status_display();
     updateStatus(statusDisplay.message, statusDisplay.color);
     updateGameState();
@@ -144,29 +376,34 @@ function handleReset() {
 function handleSquareClick(squareIndex) {
     if (!game || game.game_over) return;
     
-    const pieceIndex = game.handle_square_click(squareIndex);
-    if (pieceIndex !== null && pieceIndex !== undefined) {
-        if (game.make_move(pieceIndex)) {
-            updateGameState();
+    if (pendingChoice) {
+        const { seat, piece, moves } = pendingChoice;
+        const move = moves.find((m) => Math.min(m.to, 36) - 1 === squareIndex);
+        clearChoice();
+        if (move) {
+            if (game.make_move_piece_by(seat, piece, move.steps)) {
+                updateGameState();
+                announce(game.get_last_move_announcement());
+            }
+            return;
         }
     }
+    
+    const click = game.handle_square_click(squareIndex);
+    if (click) {
+        playPiece(click.seat, click.piece);
+    }
 }
 
-function handlePieceClick(pieceIndex) {
+function handleAreaPieceClick(seat, pieceIndex) {
     if (!game || game.game_over) return;
     
-    if (game.make_move(pieceIndex)) {
-        updateGameState();
-    }
+    playPiece(seat, pieceIndex);
 }
 
 function handleResize() {
-    const board = document.getElementById('game-board');
-    if (board) {
-        boardSize = board.offsetWidth || 600;
-        if (game) {
-            updateGameState();
-        }
+    if (game) {
+        updateGameState();
     }
 }
 
@@ -176,6 +413,7 @@ document.addEventListener('DOMContentLoaded', () => {
     
     document.getElementById('roll-btn').onclick = handleRollDice;
     document.getElementById('reset-btn').onclick = handleReset;
+    document.getElementById('game-board').addEventListener('keydown', handleBoardKey);
     
     window.addEventListener('resize', handleResize);
     handleResize();
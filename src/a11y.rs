@@ -0,0 +1,127 @@
+//! Text for screen readers: a description of the position, announcements of
+//! moves, and the pieces a keyboard user can step through and play.
+
+use serde::Serialize;
+
+use crate::rules::HOME;
+use crate::{GameState, MoveOutcome, PartialMove, Player};
+
+/// A move that can be played from the keyboard, with what playing it would do
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FocusTarget {
+    pub player: Player,
+    pub piece: usize,
+    /// All of a die, or part of one when the roll may be split
+    pub steps: u8,
+    pub from: usize,
+    pub to: usize,
+    /// Spoken description, e.g. "Piece on square 4, moves to square 9"
+    pub description: String,
+}
+
+/// The whole position in one sentence, e.g. "Light: 2 pieces at start, pieces
+/// on squares 4, 11, 30, 2 home; Dark: …; Dark to move, rolled 5"
+pub fn describe_position(state: &GameState) -> String {
+    let locale = state.locale();
+    let list = locale.message("a11y.list_separator");
+
+    let mut groups: Vec<String> = state.players()
+        .map(|player| {
+            let pieces = state.pieces(player);
+            let at_start = pieces.iter().filter(|&&pos| pos == 0).count();
+            let home = pieces.iter().filter(|&&pos| pos >= HOME).count();
+            let mut squares: Vec<usize> = pieces.iter().copied().filter(|&pos| pos > 0 && pos < HOME).collect();
+            squares.sort_unstable();
+
+            let mut details = Vec::new();
+            if at_start > 0 {
+                details.push(locale.format("a11y.at_start", &[("count", &at_start.to_string())]));
+            }
+            if !squares.is_empty() {
+                let squares: Vec<String> = squares.iter().map(|square| square.to_string()).collect();
+                details.push(locale.format("a11y.on_squares", &[("squares", &squares.join(list))]));
+            }
+            if home > 0 {
+                details.push(locale.format("a11y.home", &[("count", &home.to_string())]));
+            }
+            locale.format("a11y.player_summary", &[("player", locale.player_name(player)), ("details", &details.join(list))])
+        })
+        .collect();
+
+    let player = locale.player_name(state.current_player());
+    groups.push(if state.game_over() {
+        state.get_status_message()
+    } else if state.dice().is_empty() {
+        locale.format("a11y.to_move", &[("player", player)])
+    } else {
+        let dice: Vec<String> = state.dice().iter().map(|die| die.to_string()).collect();
+        locale.format("a11y.to_move_rolled", &[("player", player), ("dice", &dice.join(list))])
+    });
+    groups.join(locale.message("a11y.group_separator"))
+}
+
+/// Spoken announcement of a move, e.g. "Light moves from square 4 to square 9.
+/// Dark's piece is sent back to start."
+pub fn announce(state: &GameState, outcome: &MoveOutcome) -> String {
+    let locale = state.locale();
+    let moved = outcome.moved;
+    let player = locale.player_name(moved.player);
+    let (from, to, count) = (moved.from.to_string(), moved.to.to_string(), outcome.stack.to_string());
+
+    let mut sentences = vec![if moved.from == 0 {
+        locale.format("a11y.enters", &[("player", player), ("to", &to)])
+    } else if moved.to >= HOME {
+        locale.format("a11y.moves_home", &[("player", player), ("from", &from)])
+    } else if outcome.stack > 1 {
+        locale.format("a11y.moves_stack", &[("player", player), ("count", &count), ("from", &from), ("to", &to)])
+    } else {
+        locale.format("a11y.moves", &[("player", player), ("from", &from), ("to", &to)])
+    }];
+    if let Some(captured) = outcome.captured {
+        let player = locale.player_name(captured.player);
+        sentences.push(match captured.to {
+            0 => locale.format("a11y.sent_to_start", &[("player", player)]),
+            square => locale.format("a11y.sent_to_square", &[("player", player), ("square", &square.to_string())]),
+        });
+    }
+    sentences.join(" ")
+}
+
+/// Moves the current player can make, one per square and destination (pieces
+/// sharing a square, such as those waiting at the start, would all move the
+/// same way), in board order. A piece that can move by different dice or parts
+/// of a split roll has one target for each destination.
+pub fn focus_targets(state: &GameState) -> Vec<FocusTarget> {
+    let locale = state.locale();
+    let mut targets: Vec<FocusTarget> = Vec::new();
+
+    for PartialMove { player: owner, piece, steps, to } in state.partial_moves() {
+        let from = state.pieces(owner)[piece];
+        if targets.iter().any(|target| target.player == owner && target.from == from && target.to == to) {
+            continue;
+        }
+        let mut next = state.clone();
+        if !next.make_move_piece_by(owner, piece, steps) {
+            continue;
+        }
+        let Some(outcome) = next.last_move() else { continue };
+
+        let mut description = if from == 0 {
+            locale.format("a11y.focus_start", &[("to", &to.to_string())])
+        } else if to >= HOME {
+            locale.format("a11y.focus_home", &[("from", &from.to_string())])
+        } else {
+            locale.format("a11y.focus_square", &[("from", &from.to_string()), ("to", &to.to_string())])
+        };
+        if let Some(captured) = outcome.captured {
+            description = locale.format("a11y.focus_captures", &[
+                ("target", &description),
+                ("player", locale.player_name(captured.player)),
+            ]);
+        }
+        targets.push(FocusTarget { player: owner, piece, steps, from, to, description });
+    }
+
+    targets.sort_by_key(|target| (target.from, target.player.index(), target.to));
+    targets
+}
@@ -0,0 +1,245 @@
+use std::cell::Cell;
+use std::fmt::Debug;
+use std::rc::Rc;
+
+use serde::{Deserialize, Serialize};
+
+/// Where the game clock reads the time from. Swap in a `ManualClock` to drive
+/// clocks by hand (e.g. in native tests) instead of waiting for real time.
+pub trait TimeSource: Debug {
+    /// Current time in milliseconds (any fixed origin)
+    fn now_ms(&self) -> f64;
+}
+
+/// Wall-clock time: `Date.now()` in the browser, the system clock natively
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl TimeSource for SystemClock {
+    #[cfg(target_arch = "wasm32")]
+    fn now_ms(&self) -> f64 {
+        js_sys::Date::now()
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn now_ms(&self) -> f64 {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0.0, |d| d.as_secs_f64() * 1000.0)
+    }
+}
+
+/// A clock that only moves when told to. Clones share the same time.
+#[derive(Debug, Clone, Default)]
+pub struct ManualClock {
+    now: Rc<Cell<f64>>,
+}
+
+impl ManualClock {
+    pub fn new(start_ms: f64) -> ManualClock {
+        ManualClock { now: Rc::new(Cell::new(start_ms)) }
+    }
+
+    pub fn advance(&self, ms: f64) {
+        self.now.set(self.now.get() + ms);
+    }
+
+    pub fn set(&self, ms: f64) {
+        self.now.set(ms);
+    }
+}
+
+impl TimeSource for ManualClock {
+    fn now_ms(&self) -> f64 {
+        self.now.get()
+    }
+}
+
+/// Time allowed per player
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TimeControl {
+    /// Starting time on each player's clock
+    pub initial_ms: f64,
+    /// Added to a player's clock after each of their turns (Fischer increment)
+    pub increment_ms: f64,
+    /// Grace period at the start of each turn before the clock runs (simple delay)
+    pub delay_ms: f64,
+}
+
+impl Default for TimeControl {
+    fn default() -> Self {
+        TimeControl {
+            initial_ms: 5.0 * 60_000.0,
+            increment_ms: 0.0,
+            delay_ms: 0.0,
+        }
+    }
+}
+
+/// Per-player clocks. Only one clock runs at a time: the one of the player to move.
+#[derive(Debug, Clone)]
+pub struct GameClock {
+    control: TimeControl,
+    remaining: Vec<f64>,
+    /// Seat whose clock is running and when their turn started
+    running: Option<(usize, f64)>,
+}
+
+impl GameClock {
+    pub fn new(control: TimeControl, players: usize) -> GameClock {
+        GameClock {
+            control,
+            remaining: vec![control.initial_ms; players],
+            running: None,
+        }
+    }
+
+    pub fn control(&self) -> TimeControl {
+        self.control
+    }
+
+    /// Seat whose clock is running
+    pub fn running(&self) -> Option<usize> {
+        self.running.map(|(seat, _)| seat)
+    }
+
+    /// Time used so far this turn, after the delay
+    fn charged(&self, now: f64) -> f64 {
+        match self.running {
+            Some((_, started)) => (now - started - self.control.delay_ms).max(0.0),
+            None => 0.0,
+        }
+    }
+
+    /// Time left on a seat's clock at `now` (never negative)
+    pub fn remaining(&self, seat: usize, now: f64) -> f64 {
+        let banked = self.remaining.get(seat).copied().unwrap_or(0.0);
+        match self.running {
+            Some((running, _)) if running == seat => (banked - self.charged(now)).max(0.0),
+            _ => banked.max(0.0),
+        }
+    }
+
+    /// Time left of this turn's delay
+    pub fn delay_remaining(&self, now: f64) -> f64 {
+        match self.running {
+            Some((_, started)) => (self.control.delay_ms - (now - started)).max(0.0),
+            None => 0.0,
+        }
+    }
+
+    /// Start a seat's turn
+    pub fn start_turn(&mut self, seat: usize, now: f64) {
+        self.running = Some((seat, now));
+    }
+
+    /// End the running turn, charging the time used and adding the increment
+    /// if the player finished in time. Returns false if their flag fell.
+    pub fn end_turn(&mut self, now: f64, add_increment: bool) -> bool {
+        let Some((seat, _)) = self.running else {
+            return true;
+        };
+        let left = self.remaining[seat] - self.charged(now);
+        self.running = None;
+
+        if left <= 0.0 {
+            self.remaining[seat] = 0.0;
+            return false;
+        }
+        self.remaining[seat] = left + if add_increment { self.control.increment_ms } else { 0.0 };
+        true
+    }
+
+    /// Seat whose flag has fallen, if any
+    pub fn flagged(&self, now: f64) -> Option<usize> {
+        let (seat, _) = self.running?;
+        (self.remaining(seat, now) <= 0.0).then_some(seat)
+    }
+
+    /// Restart the running turn's timestamp (after swapping time sources)
+    pub fn restamp(&mut self, now: f64) {
+        if let Some((seat, _)) = self.running {
+            self.running = Some((seat, now));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::result::GameResult;
+    use crate::setup::GameSetup;
+    use crate::GameState;
+
+    /// A timed two-player game reading time from the returned clock
+    fn timed_game(control: TimeControl) -> (GameState, ManualClock) {
+        let clock = ManualClock::new(0.0);
+        let mut state = GameState::with_setup(GameSetup { time_control: Some(control), dice_seed: Some(7), ..GameSetup::default() });
+        state.set_time_source(Rc::new(clock.clone()));
+        (state, clock)
+    }
+
+    #[test]
+    fn increment_is_added_after_each_turn() {
+        let mut clock = GameClock::new(TimeControl { initial_ms: 10_000.0, increment_ms: 2_000.0, delay_ms: 0.0 }, 2);
+        clock.start_turn(0, 0.0);
+        assert_eq!(clock.remaining(0, 3_000.0), 7_000.0);
+        assert!(clock.end_turn(3_000.0, true));
+        assert_eq!(clock.remaining(0, 3_000.0), 9_000.0);
+        assert_eq!(clock.remaining(1, 3_000.0), 10_000.0);
+    }
+
+    #[test]
+    fn delay_runs_before_the_clock() {
+        let mut clock = GameClock::new(TimeControl { initial_ms: 10_000.0, increment_ms: 0.0, delay_ms: 2_000.0 }, 2);
+        clock.start_turn(1, 0.0);
+        assert_eq!(clock.remaining(1, 1_500.0), 10_000.0);
+        assert_eq!(clock.delay_remaining(1_500.0), 500.0);
+        assert_eq!(clock.remaining(1, 5_000.0), 7_000.0);
+        assert_eq!(clock.delay_remaining(5_000.0), 0.0);
+    }
+
+    #[test]
+    fn passing_a_turn_charges_the_mover() {
+        let (mut state, clock) = timed_game(TimeControl { initial_ms: 10_000.0, increment_ms: 1_000.0, delay_ms: 0.0 });
+        let mover = state.current_player().index();
+
+        state.roll_dice();
+        clock.advance(4_000.0);
+        state.pass_turn();
+        assert_eq!(state.clock().unwrap().remaining(mover, clock.now_ms()), 7_000.0);
+        assert_ne!(state.current_player().index(), mover);
+    }
+
+    #[test]
+    fn flag_fall_loses_the_game() {
+        let (mut state, clock) = timed_game(TimeControl { initial_ms: 1_000.0, increment_ms: 0.0, delay_ms: 0.0 });
+        let mover = state.current_player();
+        let opponent = state.players().find(|&p| p != mover).unwrap();
+
+        clock.advance(1_000.0);
+        assert!(!state.check_clock());
+        assert_eq!(state.result(), Some(GameResult::Timeout(mover)));
+        assert_eq!(state.winner(), Some(opponent));
+        assert!(state.game_over());
+        assert_eq!(state.roll_dice(), 0);
+    }
+
+    #[test]
+    fn flag_fall_with_three_players_drops_only_that_player() {
+        let clock = ManualClock::new(0.0);
+        let control = TimeControl { initial_ms: 1_000.0, increment_ms: 0.0, delay_ms: 0.0 };
+        let mut state = GameState::with_setup(GameSetup { players: 3, time_control: Some(control), dice_seed: Some(7), ..GameSetup::default() });
+        state.set_time_source(Rc::new(clock.clone()));
+        let mover = state.current_player();
+
+        clock.advance(1_000.0);
+        assert!(!state.check_clock());
+        assert!(!state.game_over());
+        assert!(!state.is_playing(mover));
+        assert_ne!(state.current_player(), mover);
+        assert_eq!(state.clock().unwrap().running(), Some(state.current_player().index()));
+        assert!(state.roll_dice() > 0);
+    }
+}
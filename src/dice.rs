@@ -0,0 +1,48 @@
+/// Small deterministic random source (SplitMix64) for reproducible dice.
+///
+/// Used when a game is given a seed, e.g. for headless matches and replays.
+/// Without a seed the game falls back to `Math.random()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SeededDice {
+    state: u64,
+}
+
+impl SeededDice {
+    pub fn new(seed: u64) -> SeededDice {
+        SeededDice { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform value in [0, 1), like `Math.random()`
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// A die throw (1-6)
+    pub fn roll(&mut self) -> u8 {
+        ((self.next_f64() * 6.0).floor() as u8) + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_throws_the_same_dice() {
+        let throws = |seed| {
+            let mut dice = SeededDice::new(seed);
+            (0..100).map(|_| dice.roll()).collect::<Vec<u8>>()
+        };
+        assert_eq!(throws(42), throws(42));
+        assert_ne!(throws(42), throws(43));
+        assert!(throws(42).iter().all(|die| (1..=6).contains(die)));
+    }
+}
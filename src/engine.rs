@@ -0,0 +1,247 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::rules::{Rules, HOME, MAX_DICE};
+use crate::{GameState, PieceMove, Player};
+
+/// Score given to a position where the player has already won
+const WIN_SCORE: f64 = 1000.0;
+
+/// Number of squares a piece still has to travel to reach the center
+fn pips(pieces: &[usize]) -> usize {
+    pieces.iter().map(|&pos| 36 - pos.min(36)).sum()
+}
+
+/// Squares (bits 1 to `HOME`) a piece at `from` can land on with some of
+/// `dice`, playing one die at a time, or part of one when the roll may be split.
+/// Pieces in the way are ignored. `dice` is sorted; results are memoized in `seen`.
+fn landings(rules: &Rules, from: usize, dice: &[u8], seen: &mut HashMap<(usize, Vec<u8>), u64>) -> u64 {
+    if from >= HOME || dice.is_empty() {
+        return 0;
+    }
+    if let Some(&squares) = seen.get(&(from, dice.to_vec())) {
+        return squares;
+    }
+
+    let mut squares = 0;
+    for (i, &die) in dice.iter().enumerate() {
+        // Dice showing the same number land on the same squares
+        if dice[..i].contains(&die) || (from == 0 && !rules.can_enter(die)) {
+            continue;
+        }
+        // A roll needed to enter a piece can't be split
+        let whole_die = !rules.split_roll || (from == 0 && !rules.entry_rolls.is_empty());
+        let fewest = if whole_die { die } else { 1 };
+        for steps in fewest..=die {
+            let Some(to) = rules.destination(from, steps as usize) else { continue };
+            let mut rest = dice.to_vec();
+            if steps < die {
+                rest[i] = die - steps;
+                rest.sort_unstable();
+            } else {
+                rest.remove(i);
+            }
+            squares |= 1 << to | landings(rules, to, &rest, seen);
+        }
+    }
+    seen.insert((from, dice.to_vec()), squares);
+    squares
+}
+
+/// Chance (0-1), for each position up to `HOME`, that one of `opponent_pieces`
+/// can land there with the opponents' next throw of `Rules::dice_count` dice
+fn hit_chances(rules: &Rules, opponent_pieces: &[usize]) -> [f64; HOME + 1] {
+    let dice_count = rules.dice_count.clamp(1, MAX_DICE) as u32;
+    let throws = 6usize.pow(dice_count);
+    let mut seen = HashMap::new();
+    let mut hitting_throws = [0usize; HOME + 1];
+
+    for throw in 0..throws {
+        let mut dice: Vec<u8> = (0..dice_count).map(|n| (throw / 6usize.pow(n) % 6) as u8 + 1).collect();
+        dice.sort_unstable();
+        let squares = opponent_pieces.iter()
+            .fold(0u64, |squares, &from| squares | landings(rules, from, &dice, &mut seen));
+        for (square, count) in hitting_throws.iter_mut().enumerate() {
+            if squares & 1 << square != 0 {
+                *count += 1;
+            }
+        }
+    }
+    hitting_throws.map(|count| count as f64 / throws as f64)
+}
+
+/// A piece that an opponent could hit with their next throw
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Exposure {
+    pub square: usize,
+    /// Chance (0-1) of being hit
+    pub chance: f64,
+}
+
+/// What a candidate move would do, for hints
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MovePreview {
+    pub piece: usize,
+    pub from: usize,
+    pub to: usize,
+    /// Opponent's piece the move would capture, and where it would go
+    pub captured: Option<PieceMove>,
+    /// The mover's (and partners') pieces left open to a hit afterwards
+    pub exposed: Vec<Exposure>,
+    /// Change in race lead (pips) over the closest opponent
+    pub lead_change: f64,
+}
+
+/// Everyone's pieces on `side`'s team
+fn side_pieces(state: &GameState, side: Player) -> Vec<usize> {
+    state
+        .players()
+        .filter(|&p| state.setup().same_team(p, side))
+        .flat_map(|p| state.pieces(p).iter().copied())
+        .collect()
+}
+
+/// Everyone's pieces on the other teams
+fn opposing_pieces(state: &GameState, side: Player) -> Vec<usize> {
+    state
+        .players()
+        .filter(|&p| !state.setup().same_team(p, side))
+        .flat_map(|p| state.pieces(p).iter().copied())
+        .collect()
+}
+
+/// Race lead in pips of `player`'s team over the closest opposing side
+pub fn pip_lead(state: &GameState, player: Player) -> f64 {
+    let setup = state.setup();
+    let closest_opponent = state
+        .players()
+        .filter(|&p| !setup.same_team(p, player))
+        .map(|p| pips(&side_pieces(state, p)))
+        .min()
+        .unwrap_or(0);
+    closest_opponent as f64 - pips(&side_pieces(state, player)) as f64
+}
+
+/// Pieces of `player`'s team that opponents could hit with their next roll
+pub fn exposure(state: &GameState, player: Player) -> Vec<Exposure> {
+    let chances = hit_chances(state.rules(), &opposing_pieces(state, player));
+    let mut exposed: Vec<Exposure> = side_pieces(state, player)
+        .into_iter()
+        .filter(|&pos| state.can_capture_at(pos))
+        .map(|square| Exposure { square, chance: chances[square] })
+        .filter(|e| e.chance > 0.0)
+        .collect();
+    exposed.sort_by_key(|e| e.square);
+    exposed.dedup_by_key(|e| e.square);
+    exposed
+}
+
+/// Consequences of the current player moving `piece_index`, or `None` if the move is illegal
+pub fn preview_move(state: &GameState, piece_index: usize) -> Option<MovePreview> {
+    preview_piece_move(state, state.current_player(), piece_index)
+}
+
+/// Consequences of the current player moving one of `owner`'s pieces (a
+/// partner's, when rolls are shared), or `None` if the move is illegal
+pub fn preview_piece_move(state: &GameState, owner: Player, piece_index: usize) -> Option<MovePreview> {
+    let player = state.current_player();
+    let mut next = state.clone();
+    if !next.make_move_piece(owner, piece_index) {
+        return None;
+    }
+    let outcome = next.last_move()?;
+
+    Some(MovePreview {
+        piece: piece_index,
+        from: outcome.moved.from,
+        to: outcome.moved.to,
+        captured: outcome.captured,
+        exposed: exposure(&next, player),
+        lead_change: pip_lead(&next, player) - pip_lead(state, player),
+    })
+}
+
+/// Static evaluation of a position from `player`'s point of view.
+///
+/// The score is the race lead in pips over the closest opponent, minus the
+/// expected number of squares lost to captures before `player` moves again.
+/// Positive is good for `player`.
+pub fn evaluate(state: &GameState, player: Player) -> f64 {
+    if state.game_over() {
+        return match state.winner() {
+            Some(w) if state.setup().same_team(w, player) => WIN_SCORE,
+            Some(_) => -WIN_SCORE,
+            None => 0.0,
+        };
+    }
+
+    let own = side_pieces(state, player);
+    let opponents = opposing_pieces(state, player);
+    let lead = pip_lead(state, player);
+
+    // Expected progress lost if a piece is hit.
+    // Only the side about to move gets to hit, so risk applies to the other side.
+    let risk = |pieces: &[usize], hitters: &[usize]| -> f64 {
+        let chances = hit_chances(state.rules(), hitters);
+        pieces
+            .iter()
+            .filter(|&&pos| state.can_capture_at(pos))
+            .map(|&pos| chances[pos] * pos as f64)
+            .sum()
+    };
+
+    if state.setup().same_team(state.current_player(), player) {
+        lead + risk(&opponents, &own)
+    } else {
+        lead - risk(&own, &opponents)
+    }
+}
+
+/// Evaluation after the current player moves `piece_index`, or `None` if the move is illegal
+pub fn evaluate_move(state: &GameState, piece_index: usize) -> Option<f64> {
+    let player = state.current_player();
+    let mut next = state.clone();
+    if !next.make_move(piece_index) {
+        return None;
+    }
+    Some(evaluate(&next, player))
+}
+
+/// The current player's best piece to move with the current roll
+pub fn best_move(state: &GameState) -> Option<usize> {
+    state
+        .valid_moves()
+        .into_iter()
+        .filter_map(|i| evaluate_move(state, i).map(|score| (i, score)))
+        .fold(None, |best: Option<(usize, f64)>, (i, score)| match best {
+            Some((_, best_score)) if best_score >= score => best,
+            _ => Some((i, score)),
+        })
+        .map(|(i, _)| i)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hit_chances_follow_the_dice_and_rules() {
+        let one_die = Rules::default();
+        assert_eq!(hit_chances(&one_die, &[10])[12], 1.0 / 6.0);
+        assert_eq!(hit_chances(&one_die, &[10])[17], 0.0);
+
+        // A 2 on either die, or 1 then 1
+        let two_dice = Rules { dice_count: 2, ..Rules::default() };
+        assert_eq!(hit_chances(&two_dice, &[10])[12], 12.0 / 36.0);
+        assert_eq!(hit_chances(&two_dice, &[10])[22], 1.0 / 36.0);
+
+        let entry = Rules { entry_rolls: vec![6], split_roll: true, ..Rules::default() };
+        assert_eq!(hit_chances(&entry, &[0])[6], 1.0 / 6.0);
+        assert_eq!(hit_chances(&entry, &[0])[3], 0.0);
+
+        let bounce = Rules { bear_off: crate::rules::BearOff::Bounce, ..Rules::default() };
+        assert_eq!(hit_chances(&bounce, &[33])[34], 2.0 / 6.0);
+        assert_eq!(hit_chances(&Rules::default(), &[33])[34], 1.0 / 6.0);
+    }
+}
@@ -0,0 +1,149 @@
+use std::f64::consts::TAU;
+
+use serde::{Deserialize, Serialize};
+
+use crate::rules::HOME;
+
+/// Curve the squares are laid out along
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum SpiralKind {
+    /// Radius shrinks evenly with each turn, so the coils are equally spaced
+    #[default]
+    Archimedean,
+    /// Radius shrinks by the same factor each turn, so the coils tighten towards the head
+    Logarithmic,
+}
+
+/// Way the spiral winds from the tail (square 1) towards the head
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Direction {
+    #[default]
+    Clockwise,
+    CounterClockwise,
+}
+
+/// Shape of the snake. From JavaScript this is a plain object such as
+/// `{ kind: "Logarithmic", turns: 4 }`; fields left out keep their defaults.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SpiralLayout {
+    pub kind: SpiralKind,
+    /// Number of squares along the path (the last one is the head). Games are
+    /// played on 36 squares; other counts are only for drawing other boards.
+    pub squares: usize,
+    /// Number of times the path winds round the center
+    pub turns: f64,
+    /// Angle of the first square, in degrees (0 = right of the center)
+    pub start_angle: f64,
+    pub direction: Direction,
+    /// Distance of the first square from the center, as a fraction of the board radius
+    pub outer_radius: f64,
+    /// Distance of the last square from the center, as a fraction of the board radius
+    pub inner_radius: f64,
+}
+
+impl Default for SpiralLayout {
+    fn default() -> Self {
+        SpiralLayout {
+            kind: SpiralKind::default(),
+            squares: 36,
+            turns: 3.0,
+            start_angle: 0.0,
+            direction: Direction::default(),
+            outer_radius: 0.85,
+            inner_radius: 0.25,
+        }
+    }
+}
+
+/// Where a square sits on the board
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SquarePosition {
+    pub x: f64,
+    pub y: f64,
+    /// Angle round the center, in degrees (0-360)
+    pub angle: f64,
+    /// Distance from the center, as a percentage of the board radius
+    pub radius: f64,
+    /// Direction the path runs through the square, in degrees, for drawing segments
+    #[serde(default)]
+    pub tangent: f64,
+}
+
+/// Points sampled along the curve when measuring its length
+const SAMPLES: usize = 4096;
+
+impl SpiralLayout {
+    /// Check options that came from outside (e.g. JavaScript)
+    pub fn validate(&self) -> Result<(), String> {
+        if self.squares != HOME {
+            return Err(format!("The board has {} squares, not {}", HOME, self.squares));
+        }
+        if self.turns.is_nan() || self.turns <= 0.0 {
+            return Err("A spiral needs a positive number of turns".to_string());
+        }
+        let radii_ok = 0.0 < self.inner_radius && self.inner_radius < self.outer_radius && self.outer_radius <= 1.0;
+        if !radii_ok {
+            return Err("Spiral radii must satisfy 0 < inner < outer <= 1".to_string());
+        }
+        Ok(())
+    }
+
+    /// Point on the curve at `t` (0 = first square, 1 = last), relative to the
+    /// center and scaled to a unit board radius
+    fn point(&self, t: f64) -> (f64, f64) {
+        let sign = match self.direction {
+            Direction::Clockwise => 1.0,
+            Direction::CounterClockwise => -1.0,
+        };
+        let theta = self.start_angle.to_radians() + sign * t * self.turns * TAU;
+        let r = match self.kind {
+            SpiralKind::Archimedean => self.outer_radius + (self.inner_radius - self.outer_radius) * t,
+            SpiralKind::Logarithmic => self.outer_radius * (self.inner_radius / self.outer_radius).powf(t),
+        };
+        (r * theta.cos(), r * theta.sin())
+    }
+
+    /// Square positions on a board `board_size` pixels across, spaced evenly
+    /// along the length of the curve
+    pub fn positions(&self, board_size: f64) -> Vec<SquarePosition> {
+        let board_radius = board_size / 2.0;
+
+        // Sample the curve and its cumulative length
+        let samples: Vec<(f64, f64)> = (0..=SAMPLES)
+            .map(|i| self.point(i as f64 / SAMPLES as f64))
+            .collect();
+        let mut lengths = Vec::with_capacity(samples.len());
+        lengths.push(0.0);
+        for pair in samples.windows(2) {
+            let (dx, dy) = (pair[1].0 - pair[0].0, pair[1].1 - pair[0].1);
+            lengths.push(lengths[lengths.len() - 1] + dx.hypot(dy));
+        }
+        let total = lengths[SAMPLES];
+
+        let count = self.squares.max(2);
+        let mut sample = 0;
+        (0..count)
+            .map(|n| {
+                // Find the sample segment holding this arc length and interpolate within it
+                let target = total * n as f64 / (count - 1) as f64;
+                while sample < SAMPLES - 1 && lengths[sample + 1] < target {
+                    sample += 1;
+                }
+                let span = lengths[sample + 1] - lengths[sample];
+                let f = if span > 0.0 { ((target - lengths[sample]) / span).clamp(0.0, 1.0) } else { 0.0 };
+                let (x0, y0) = samples[sample];
+                let (x1, y1) = samples[sample + 1];
+                let (x, y) = (x0 + (x1 - x0) * f, y0 + (y1 - y0) * f);
+
+                SquarePosition {
+                    x: board_radius + x * board_radius,
+                    y: board_radius + y * board_radius,
+                    angle: y.atan2(x).to_degrees().rem_euclid(360.0),
+                    radius: x.hypot(y) * 100.0,
+                    tangent: (y1 - y0).atan2(x1 - x0).to_degrees().rem_euclid(360.0),
+                }
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,356 @@
+//! Message catalog for everything the game shows to players. Messages are looked
+//! up by key and filled in from named parameters, e.g. `"{player} resigned."`.
+
+use serde::{Deserialize, Serialize};
+
+use crate::Player;
+
+/// A supported language
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Locale {
+    #[default]
+    En,
+    Fr,
+    De,
+    /// Arabic, written right to left
+    Ar,
+}
+
+type Catalog = &'static [(&'static str, &'static str)];
+
+const EN: Catalog = &[
+    ("player.light", "Light"),
+    ("player.dark", "Dark"),
+    ("player.red", "Red"),
+    ("player.blue", "Blue"),
+    ("player.green", "Green"),
+    ("player.teal", "Teal"),
+    ("status.select_piece", "Select a piece to move"),
+    ("status.choose_destination", "Choose where to move the piece"),
+    ("status.no_moves", "No valid moves. Turn passes."),
+    ("status.team_turn", "{player} (Team {team}): {message}"),
+    ("result.resigned", "{player} resigned."),
+    ("result.timeout", "{player} ran out of time."),
+    ("result.player_wins", "{player} Player Wins!"),
+    ("result.team_wins", "{team} Wins!"),
+    ("game_over.outcome", "Game Over! {outcome}"),
+    ("game_over.reason", "Game Over! {reason}"),
+    ("game_over.reason_and_outcome", "Game Over! {reason} {outcome}"),
+    ("game_over.draw", "Game Over! Draw agreed."),
+    ("game_over.abandoned", "Game abandoned."),
+    ("team.name", "Team {number} ({members})"),
+    ("entry.hint", "Enters on a roll of {rolls}"),
+    ("entry.or", " or "),
+    ("bench.home", "Home: {count}"),
+    ("area.waiting", "Start: {count}"),
+    ("a11y.list_separator", ", "),
+    ("a11y.group_separator", "; "),
+    ("a11y.player_summary", "{player}: {details}"),
+    ("a11y.at_start", "{count} pieces at start"),
+    ("a11y.on_squares", "pieces on squares {squares}"),
+    ("a11y.home", "{count} home"),
+    ("a11y.to_move", "{player} to move"),
+    ("a11y.to_move_rolled", "{player} to move, rolled {dice}"),
+    ("a11y.enters", "{player} enters a piece on square {to}."),
+    ("a11y.moves", "{player} moves from square {from} to square {to}."),
+    ("a11y.moves_stack", "{player} moves {count} pieces from square {from} to square {to}."),
+    ("a11y.moves_home", "{player} brings a piece home from square {from}."),
+    ("a11y.sent_to_start", "{player}'s piece is sent back to start."),
+    ("a11y.sent_to_square", "{player}'s piece goes to square {square}."),
+    ("a11y.focus_start", "Piece at start, moves to square {to}"),
+    ("a11y.focus_square", "Piece on square {from}, moves to square {to}"),
+    ("a11y.focus_home", "Piece on square {from}, moves home"),
+    ("a11y.focus_captures", "{target}, captures {player}"),
+    ("a11y.no_targets", "No piece can move"),
+    ("ui.current", "Current:"),
+    ("ui.dice", "Dice:"),
+    ("ui.roll_dice", "Roll Dice"),
+    ("ui.reset", "Reset"),
+    ("team.separator", " & "),
+    ("dice.separator", " + "),
+    ("ui.use_die", "Move with the {die}"),
+    ("preview.lead", "Lead {lead} pips"),
+    ("preview.lead_capture", "Lead {lead} pips, captures"),
+    ("preview.exposed", "{percent}% chance of being hit"),
+];
+
+const FR: Catalog = &[
+    ("player.light", "Clair"),
+    ("player.dark", "Sombre"),
+    ("player.red", "Rouge"),
+    ("player.blue", "Bleu"),
+    ("player.green", "Vert"),
+    ("player.teal", "Turquoise"),
+    ("status.select_piece", "Choisissez un pion à déplacer"),
+    ("status.choose_destination", "Choisissez où déplacer le pion"),
+    ("status.no_moves", "Aucun coup possible. Le tour passe."),
+    ("status.team_turn", "{player} (Équipe {team}) : {message}"),
+    ("result.resigned", "{player} a abandonné."),
+    ("result.timeout", "{player} a dépassé le temps."),
+    ("result.player_wins", "Le joueur {player} gagne !"),
+    ("result.team_wins", "{team} gagne !"),
+    ("game_over.outcome", "Partie terminée ! {outcome}"),
+    ("game_over.reason", "Partie terminée ! {reason}"),
+    ("game_over.reason_and_outcome", "Partie terminée ! {reason} {outcome}"),
+    ("game_over.draw", "Partie terminée ! Nulle acceptée."),
+    ("game_over.abandoned", "Partie abandonnée."),
+    ("team.name", "Équipe {number} ({members})"),
+    ("entry.hint", "Entre sur un lancer de {rolls}"),
+    ("entry.or", " ou "),
+    ("bench.home", "Arrivés : {count}"),
+    ("area.waiting", "Départ : {count}"),
+    ("a11y.list_separator", ", "),
+    ("a11y.group_separator", "; "),
+    ("a11y.player_summary", "{player} : {details}"),
+    ("a11y.at_start", "{count} pions au départ"),
+    ("a11y.on_squares", "pions sur les cases {squares}"),
+    ("a11y.home", "{count} arrivés"),
+    ("a11y.to_move", "{player} joue"),
+    ("a11y.to_move_rolled", "{player} joue, a lancé {dice}"),
+    ("a11y.enters", "{player} entre un pion sur la case {to}."),
+    ("a11y.moves", "{player} va de la case {from} à la case {to}."),
+    ("a11y.moves_stack", "{player} déplace {count} pions de la case {from} à la case {to}."),
+    ("a11y.moves_home", "{player} amène un pion à l'arrivée depuis la case {from}."),
+    ("a11y.sent_to_start", "Le pion {player} retourne au départ."),
+    ("a11y.sent_to_square", "Le pion {player} va sur la case {square}."),
+    ("a11y.focus_start", "Pion au départ, va sur la case {to}"),
+    ("a11y.focus_square", "Pion sur la case {from}, va sur la case {to}"),
+    ("a11y.focus_home", "Pion sur la case {from}, va à l'arrivée"),
+    ("a11y.focus_captures", "{target}, prend {player}"),
+    ("a11y.no_targets", "Aucun pion ne peut bouger"),
+    ("ui.current", "Joueur :"),
+    ("ui.dice", "Dé :"),
+    ("ui.roll_dice", "Lancer le dé"),
+    ("ui.reset", "Recommencer"),
+    ("team.separator", " & "),
+    ("dice.separator", " + "),
+    ("ui.use_die", "Jouer le {die}"),
+    ("preview.lead", "Avance {lead} cases"),
+    ("preview.lead_capture", "Avance {lead} cases, prise"),
+    ("preview.exposed", "{percent} % de risque d'être pris"),
+];
+
+const DE: Catalog = &[
+    ("player.light", "Hell"),
+    ("player.dark", "Dunkel"),
+    ("player.red", "Rot"),
+    ("player.blue", "Blau"),
+    ("player.green", "Grün"),
+    ("player.teal", "Türkis"),
+    ("status.select_piece", "Wähle eine Figur zum Ziehen"),
+    ("status.choose_destination", "Wähle, wohin die Figur zieht"),
+    ("status.no_moves", "Kein gültiger Zug. Der Nächste ist dran."),
+    ("status.team_turn", "{player} (Team {team}): {message}"),
+    ("result.resigned", "{player} hat aufgegeben."),
+    ("result.timeout", "{player} hat die Zeit überschritten."),
+    ("result.player_wins", "Spieler {player} gewinnt!"),
+    ("result.team_wins", "{team} gewinnt!"),
+    ("game_over.outcome", "Spiel vorbei! {outcome}"),
+    ("game_over.reason", "Spiel vorbei! {reason}"),
+    ("game_over.reason_and_outcome", "Spiel vorbei! {reason} {outcome}"),
+    ("game_over.draw", "Spiel vorbei! Remis vereinbart."),
+    ("game_over.abandoned", "Spiel abgebrochen."),
+    ("team.name", "Team {number} ({members})"),
+    ("entry.hint", "Kommt mit einem Wurf von {rolls} ins Spiel"),
+    ("entry.or", " oder "),
+    ("bench.home", "Im Ziel: {count}"),
+    ("area.waiting", "Am Start: {count}"),
+    ("a11y.list_separator", ", "),
+    ("a11y.group_separator", "; "),
+    ("a11y.player_summary", "{player}: {details}"),
+    ("a11y.at_start", "{count} Figuren am Start"),
+    ("a11y.on_squares", "Figuren auf den Feldern {squares}"),
+    ("a11y.home", "{count} im Ziel"),
+    ("a11y.to_move", "{player} ist am Zug"),
+    ("a11y.to_move_rolled", "{player} ist am Zug, hat {dice} gewürfelt"),
+    ("a11y.enters", "{player} setzt eine Figur auf Feld {to}."),
+    ("a11y.moves", "{player} zieht von Feld {from} auf Feld {to}."),
+    ("a11y.moves_stack", "{player} zieht {count} Figuren von Feld {from} auf Feld {to}."),
+    ("a11y.moves_home", "{player} bringt eine Figur von Feld {from} ins Ziel."),
+    ("a11y.sent_to_start", "Die Figur von {player} muss zurück zum Start."),
+    ("a11y.sent_to_square", "Die Figur von {player} kommt auf Feld {square}."),
+    ("a11y.focus_start", "Figur am Start, zieht auf Feld {to}"),
+    ("a11y.focus_square", "Figur auf Feld {from}, zieht auf Feld {to}"),
+    ("a11y.focus_home", "Figur auf Feld {from}, zieht ins Ziel"),
+    ("a11y.focus_captures", "{target}, schlägt {player}"),
+    ("a11y.no_targets", "Keine Figur kann ziehen"),
+    ("ui.current", "Am Zug:"),
+    ("ui.dice", "Würfel:"),
+    ("ui.roll_dice", "Würfeln"),
+    ("ui.reset", "Neues Spiel"),
+    ("team.separator", " & "),
+    ("dice.separator", " + "),
+    ("ui.use_die", "Mit der {die} ziehen"),
+    ("preview.lead", "Vorsprung {lead} Felder"),
+    ("preview.lead_capture", "Vorsprung {lead} Felder, schlägt"),
+    ("preview.exposed", "{percent} % Gefahr, geschlagen zu werden"),
+];
+
+const AR: Catalog = &[
+    ("player.light", "الفاتح"),
+    ("player.dark", "الداكن"),
+    ("player.red", "الأحمر"),
+    ("player.blue", "الأزرق"),
+    ("player.green", "الأخضر"),
+    ("player.teal", "الفيروزي"),
+    ("status.select_piece", "اختر قطعة لتحريكها"),
+    ("status.choose_destination", "اختر إلى أين تتحرك القطعة"),
+    ("status.no_moves", "لا توجد حركات صالحة. ينتقل الدور."),
+    ("status.team_turn", "{player} (الفريق {team}): {message}"),
+    ("result.resigned", "استسلم {player}."),
+    ("result.timeout", "انتهى وقت {player}."),
+    ("result.player_wins", "فاز اللاعب {player}!"),
+    ("result.team_wins", "فاز {team}!"),
+    ("game_over.outcome", "انتهت اللعبة! {outcome}"),
+    ("game_over.reason", "انتهت اللعبة! {reason}"),
+    ("game_over.reason_and_outcome", "انتهت اللعبة! {reason} {outcome}"),
+    ("game_over.draw", "انتهت اللعبة! تم الاتفاق على التعادل."),
+    ("game_over.abandoned", "أُلغيت اللعبة."),
+    ("team.name", "الفريق {number} ({members})"),
+    ("entry.hint", "تدخل برمية {rolls}"),
+    ("entry.or", " أو "),
+    ("bench.home", "وصلت: {count}"),
+    ("area.waiting", "في البداية: {count}"),
+    ("a11y.list_separator", "، "),
+    ("a11y.group_separator", "؛ "),
+    ("a11y.player_summary", "{player}: {details}"),
+    ("a11y.at_start", "{count} قطع في البداية"),
+    ("a11y.on_squares", "قطع على المربعات {squares}"),
+    ("a11y.home", "{count} وصلت"),
+    ("a11y.to_move", "دور {player}"),
+    ("a11y.to_move_rolled", "دور {player}، رمى {dice}"),
+    ("a11y.enters", "أدخل {player} قطعة إلى المربع {to}."),
+    ("a11y.moves", "حرّك {player} قطعة من المربع {from} إلى المربع {to}."),
+    ("a11y.moves_stack", "حرّك {player} {count} قطع من المربع {from} إلى المربع {to}."),
+    ("a11y.moves_home", "أوصل {player} قطعة من المربع {from}."),
+    ("a11y.sent_to_start", "عادت قطعة {player} إلى البداية."),
+    ("a11y.sent_to_square", "انتقلت قطعة {player} إلى المربع {square}."),
+    ("a11y.focus_start", "قطعة في البداية، تنتقل إلى المربع {to}"),
+    ("a11y.focus_square", "قطعة على المربع {from}، تنتقل إلى المربع {to}"),
+    ("a11y.focus_home", "قطعة على المربع {from}، تصل إلى النهاية"),
+    ("a11y.focus_captures", "{target}، تأسر {player}"),
+    ("a11y.no_targets", "لا توجد قطعة يمكن تحريكها"),
+    ("ui.current", "الدور:"),
+    ("ui.dice", "النرد:"),
+    ("ui.roll_dice", "ارمِ النرد"),
+    ("ui.reset", "إعادة"),
+    ("team.separator", " و"),
+    ("dice.separator", " + "),
+    ("ui.use_die", "العب بالـ {die}"),
+    ("preview.lead", "التقدم {lead} مربعات"),
+    ("preview.lead_capture", "التقدم {lead} مربعات، مع أسر"),
+    ("preview.exposed", "احتمال الأسر {percent}٪"),
+];
+
+impl Locale {
+    pub const ALL: [Locale; 4] = [Locale::En, Locale::Fr, Locale::De, Locale::Ar];
+
+    /// BCP 47 language code, e.g. `"fr"`
+    pub fn code(self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            Locale::Fr => "fr",
+            Locale::De => "de",
+            Locale::Ar => "ar",
+        }
+    }
+
+    /// Locale for a language code; regional variants such as `"fr-CA"` use the base language
+    pub fn from_code(code: &str) -> Option<Locale> {
+        let language = code.split(['-', '_']).next().unwrap_or_default().to_ascii_lowercase();
+        Locale::ALL.into_iter().find(|locale| locale.code() == language)
+    }
+
+    pub fn is_rtl(self) -> bool {
+        self == Locale::Ar
+    }
+
+    /// Text direction for the HTML `dir` attribute
+    pub fn direction(self) -> &'static str {
+        if self.is_rtl() { "rtl" } else { "ltr" }
+    }
+
+    fn catalog(self) -> Catalog {
+        match self {
+            Locale::En => EN,
+            Locale::Fr => FR,
+            Locale::De => DE,
+            Locale::Ar => AR,
+        }
+    }
+
+    /// Raw message template, falling back to English and then to the key itself
+    pub fn message(self, key: &str) -> &str {
+        let find = |catalog: Catalog| catalog.iter().find(|(k, _)| *k == key).map(|&(_, text)| text);
+        find(self.catalog()).or_else(|| find(EN)).unwrap_or(key)
+    }
+
+    /// Message with its `{name}` parameters filled in from `args`
+    pub fn format(self, key: &str, args: &[(&str, &str)]) -> String {
+        let template = self.message(key);
+        let mut out = String::with_capacity(template.len());
+        let mut rest = template;
+        while let Some(open) = rest.find('{') {
+            out.push_str(&rest[..open]);
+            let after = &rest[open + 1..];
+            match after.find('}') {
+                Some(close) => {
+                    let name = &after[..close];
+                    match args.iter().find(|(arg, _)| *arg == name) {
+                        Some((_, value)) => out.push_str(value),
+                        None => out.push_str(&rest[open..open + close + 2]),
+                    }
+                    rest = &after[close + 1..];
+                }
+                None => {
+                    out.push_str(&rest[open..]);
+                    rest = "";
+                }
+            }
+        }
+        out.push_str(rest);
+        out
+    }
+
+    /// A player's colour name in this language
+    pub fn player_name(self, player: Player) -> &'static str {
+        self.message(match player {
+            Player::Light => "player.light",
+            Player::Dark => "player.dark",
+            Player::Red => "player.red",
+            Player::Blue => "player.blue",
+            Player::Green => "player.green",
+            Player::Teal => "player.teal",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parameters(template: &str) -> Vec<&str> {
+        let mut names: Vec<&str> = template.split('{').skip(1).filter_map(|part| part.split('}').next()).collect();
+        names.sort_unstable();
+        names
+    }
+
+    #[test]
+    fn every_key_exists_in_every_locale() {
+        for locale in Locale::ALL {
+            for (key, english) in EN {
+                let text = locale.catalog().iter().find(|(k, _)| k == key).map(|&(_, text)| text);
+                let text = text.unwrap_or_else(|| panic!("{} is missing {}", locale.code(), key));
+                assert_eq!(parameters(text), parameters(english), "{} {} has different parameters", locale.code(), key);
+            }
+            assert_eq!(locale.catalog().len(), EN.len(), "{} has keys English doesn't", locale.code());
+        }
+    }
+
+    #[test]
+    fn parameters_are_filled_in() {
+        assert_eq!(Locale::En.format("result.resigned", &[("player", "Dark")]), "Dark resigned.");
+        assert_eq!(Locale::Fr.format("team.name", &[("number", "2"), ("members", "Clair & Rouge")]), "Équipe 2 (Clair & Rouge)");
+        assert_eq!(Locale::En.format("bench.home", &[]), "Home: {count}");
+    }
+}
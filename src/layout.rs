@@ -0,0 +1,171 @@
+//! Sizes for the HTML front end, worked out from the space the page has for
+//! the board: the board itself, its squares, the start area and the fonts.
+
+use serde::{Deserialize, Serialize};
+
+use crate::geometry::SquarePosition;
+use crate::render;
+
+/// Smallest and largest board, in CSS pixels
+const MIN_BOARD: f64 = 240.0;
+const MAX_BOARD: f64 = 900.0;
+/// Space kept clear around the board and between it and the start area
+const GAP: f64 = 12.0;
+/// Padding inside the start area
+const PADDING: f64 = 10.0;
+/// Start pieces, in CSS pixels (they scale with the board in between)
+const MIN_START_PIECE: f64 = 28.0;
+const MAX_START_PIECE: f64 = 48.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Orientation {
+    #[default]
+    Landscape,
+    Portrait,
+}
+
+impl Orientation {
+    /// From a CSS/Screen Orientation name such as `"portrait-primary"`; anything
+    /// else is worked out from the viewport's shape
+    pub fn parse(name: &str, width: f64, height: f64) -> Orientation {
+        if name.starts_with("portrait") {
+            Orientation::Portrait
+        } else if name.starts_with("landscape") || width >= height {
+            Orientation::Landscape
+        } else {
+            Orientation::Portrait
+        }
+    }
+}
+
+/// The space the board and start area may fill, in CSS pixels
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Viewport {
+    pub width: f64,
+    pub height: f64,
+    /// Device pixels per CSS pixel
+    pub dpr: f64,
+    pub orientation: Orientation,
+}
+
+/// Where the start area goes relative to the board
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Placement {
+    Below,
+    /// To the right of the board
+    Side,
+}
+
+/// Size and position of a box, in CSS pixels from the top left of the viewport
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Rect {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+/// Font sizes in CSS pixels
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct FontSizes {
+    /// Glyph on a piece on the board
+    pub piece: f64,
+    pub square_number: f64,
+    pub start_piece: f64,
+    pub status: f64,
+}
+
+/// Everything the front end needs to size the board
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Layout {
+    pub board: Rect,
+    /// Board side in CSS pixels (a whole number of device pixels)
+    pub board_size: f64,
+    /// Board side in device pixels, for sizing a canvas
+    pub device_board_size: f64,
+    /// Side of a square; neighbouring squares never overlap at this size
+    pub square_size: f64,
+    /// The snake's head in the middle of the board
+    pub head_width: f64,
+    pub head_height: f64,
+    pub placement: Placement,
+    /// Every player's area, one row (or column, at the side) each
+    pub start_area: Rect,
+    /// Height of a row (or width of a column) of the start area
+    pub bench_size: f64,
+    pub start_piece_size: f64,
+    pub fonts: FontSizes,
+}
+
+impl Layout {
+    /// Fit the board, and a start area with `benches` rows of `start_pieces`
+    /// pieces, into the viewport, choosing whichever placement of the start
+    /// area leaves the larger board. Portrait screens always put it below.
+    /// `positions` gives the squares on a board of a given size (e.g.
+    /// `GameState::spiral_positions`).
+    pub fn solve(
+        viewport: Viewport,
+        positions: impl FnOnce(f64) -> Vec<SquarePosition>,
+        benches: usize,
+        start_pieces: usize,
+    ) -> Layout {
+        let dpr = if viewport.dpr > 0.0 { viewport.dpr } else { 1.0 };
+        let width = viewport.width.max(0.0);
+        let height = viewport.height.max(0.0);
+
+        // Start pieces are sized from the largest board that could fit, so the
+        // start area never turns out bigger than the room left for it
+        let bound = (width.min(height) - 2.0 * GAP).clamp(MIN_BOARD, MAX_BOARD);
+        let piece = (bound * 0.07).clamp(MIN_START_PIECE, MAX_START_PIECE);
+        let bench_size = piece + 2.0 * PADDING;
+        let thickness = bench_size * benches.max(1) as f64;
+
+        let below = (width - 2.0 * GAP).min(height - 3.0 * GAP - thickness);
+        let side = (width - 3.0 * GAP - thickness).min(height - 2.0 * GAP);
+        let (placement, board_size) = match viewport.orientation {
+            Orientation::Landscape if side > below => (Placement::Side, side),
+            _ => (Placement::Below, below),
+        };
+
+        // Whole device pixels keep a canvas sharp
+        let board_size = board_size.clamp(MIN_BOARD, MAX_BOARD);
+        let device_board_size = (board_size * dpr).floor();
+        let board_size = device_board_size / dpr;
+
+        let square_size = render::square_size(&positions(board_size), board_size);
+        let board = Rect { x: GAP, y: GAP, width: board_size, height: board_size };
+        let start_area = match placement {
+            Placement::Below => Rect { x: GAP, y: 2.0 * GAP + board_size, width: board_size, height: thickness },
+            Placement::Side => Rect { x: 2.0 * GAP + board_size, y: GAP, width: thickness, height: board_size },
+        };
+
+        // Shrink start pieces that wouldn't all fit in a row
+        let room = match placement {
+            Placement::Below => start_area.width,
+            Placement::Side => start_area.height,
+        } - 2.0 * PADDING;
+        let start_piece_size = match start_pieces {
+            0 => piece,
+            n => piece.min(room / n as f64 - GAP / 2.0),
+        };
+
+        Layout {
+            board,
+            board_size,
+            device_board_size,
+            square_size,
+            head_width: square_size * 1.3,
+            head_height: square_size,
+            placement,
+            start_area,
+            bench_size,
+            start_piece_size,
+            fonts: FontSizes {
+                piece: square_size * 0.55,
+                square_number: (square_size * 0.2).max(8.0),
+                start_piece: start_piece_size * 0.6,
+                status: (board_size * 0.035).clamp(14.0, 24.0),
+            },
+        }
+    }
+}
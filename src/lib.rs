@@ -1,17 +1,42 @@
 use wasm_bindgen::prelude::*;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-struct SquarePosition {
-    x: f64,
-    y: f64,
-    angle: f64,
-    radius: f64,
-}
+pub mod a11y;
+pub mod clock;
+pub mod dice;
+pub mod engine;
+pub mod geometry;
+pub mod i18n;
+pub mod layout;
+pub mod puzzle;
+pub mod render;
+pub mod result;
+pub mod rules;
+pub mod setup;
+pub mod stats;
+pub mod theme;
+pub mod tournament;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use a11y::FocusTarget;
+use clock::{GameClock, SystemClock, TimeSource};
+use dice::SeededDice;
+use engine::MovePreview;
+use geometry::{SpiralLayout, SquarePosition};
+use i18n::Locale;
+use layout::{Layout, Orientation, Viewport};
+use result::GameResult;
+use rules::{Capture, Rules, Stacking};
+use setup::{GameSetup, StartingPlayer};
+use stats::{GameStats, PlayerStats};
+use theme::Theme;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct SquareData {
     square_type: u8,
+    stack: usize, // Pieces on the square (more than one only when stacking)
     is_valid_move: bool,
     is_center: bool,
 }
@@ -28,37 +53,202 @@ struct StartPiece {
     is_valid_move: bool,
 }
 
+/// A move of one piece by all or part of the roll (see `Rules::split_roll`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartialMove {
+    pub player: Player,
+    pub piece: usize,
+    pub steps: u8,
+    pub to: usize,
+}
+
+/// A piece going from one square to another
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PieceMove {
+    pub player: Player,
+    pub piece: usize,
+    pub from: usize,
+    pub to: usize,
+}
+
+/// What a move did: the piece moved and the piece it captured, if any
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MoveOutcome {
+    pub moved: PieceMove,
+    /// Pieces that moved together (more than one when a stack moved)
+    pub stack: usize,
+    pub captured: Option<PieceMove>,
+}
+
+/// Most players a game can seat
+pub const MAX_PLAYERS: usize = 6;
+
+/// A seat at the board. Two-player games use Light and Dark; larger games
+/// seat players in this order.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[wasm_bindgen]
 pub enum Player {
     Light,
     Dark,
+    Red,
+    Blue,
+    Green,
+    Teal,
+}
+
+impl Player {
+    /// All seats in turn order
+    pub const ALL: [Player; MAX_PLAYERS] = [
+        Player::Light,
+        Player::Dark,
+        Player::Red,
+        Player::Blue,
+        Player::Green,
+        Player::Teal,
+    ];
+    
+    pub fn index(self) -> usize {
+        self as usize
+    }
+    
+    pub fn from_index(index: usize) -> Option<Player> {
+        Player::ALL.get(index).copied()
+    }
+    
+    /// Display name
+    pub fn name(self) -> &'static str {
+        match self {
+            Player::Light => "Light",
+            Player::Dark => "Dark",
+            Player::Red => "Red",
+            Player::Blue => "Blue",
+            Player::Green => "Green",
+            Player::Teal => "Teal",
+        }
+    }
+    
+    /// CSS class prefix (e.g. "light" for "light-piece")
+    pub fn class_name(self) -> &'static str {
+        match self {
+            Player::Light => "light",
+            Player::Dark => "dark",
+            Player::Red => "red",
+            Player::Blue => "blue",
+            Player::Green => "green",
+            Player::Teal => "teal",
+        }
+    }
+    
+    /// Piece glyph
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Player::Light => "○",
+            Player::Dark => "●",
+            Player::Red => "◆",
+            Player::Blue => "■",
+            Player::Green => "▲",
+            Player::Teal => "★",
+        }
+    }
+    
+    /// Piece colour
+    pub fn color(self) -> &'static str {
+        match self {
+            Player::Light => "#ffd700",
+            Player::Dark => "#8b4513",
+            Player::Red => "#c0392b",
+            Player::Blue => "#2e86c1",
+            Player::Green => "#27ae60",
+            Player::Teal => "#16a085",
+        }
+    }
+    
+    /// Colour of the glyph drawn on a piece
+    pub fn text_color(self) -> &'static str {
+        match self {
+            Player::Light => "#333",
+            _ => "white",
+        }
+    }
+    
+    /// Value used for this player's pieces in `get_board` (0 = empty square)
+    pub fn square_type(self) -> u8 {
+        self.index() as u8 + 1
+    }
+    
+    pub fn from_square_type(square_type: u8) -> Option<Player> {
+        (square_type as usize).checked_sub(1).and_then(Player::from_index)
+    }
+}
+
+/// One seated player and where their pieces are
+#[derive(Debug, Clone)]
+struct PlayerRecord {
+    player: Player,
+    pieces: Vec<usize>, // Positions (0 = start, 36 = center/end)
+    captures_made: u32,
+    captures_suffered: u32,
+    turns_passed: u32,
+    out: bool, // Resigned or lost on time while others play on
+}
+
+impl PlayerRecord {
+    fn new(player: Player, pieces: Vec<usize>) -> PlayerRecord {
+        PlayerRecord {
+            player,
+            pieces,
+            captures_made: 0,
+            captures_suffered: 0,
+            turns_passed: 0,
+            out: false,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
 #[wasm_bindgen]
 pub struct GameState {
     current_player: Player,
-    dice_value: u8,
-    game_over: bool,
-    winner: Option<Player>,
-    // Track piece positions for each player
-    light_pieces: Vec<usize>, // Positions of light pieces (0 = start, 36 = center/end)
-    dark_pieces: Vec<usize>,  // Positions of dark pieces
+    dice_pool: Vec<u8>, // Dice thrown this turn that are still to be used
+    result: Option<GameResult>, // How the game ended (None while in play)
+    // Track piece positions for each player, in turn order
+    players: Vec<PlayerRecord>,
+    dice: Option<SeededDice>, // Seeded dice for reproducible games (None = Math.random)
+    setup: GameSetup,
+    game_number: u32,         // Games started since construction (0 = first), for alternating starts
+    roll_off: Vec<Vec<u8>>,   // Throws (in seat order) of each roll-off round that decided the first player
+    clock: Option<GameClock>, // Player clocks (None = untimed)
+    time_source: Rc<dyn TimeSource>,
+    draw_offers: Vec<Player>, // Players offering a draw since the last move
+    entered_this_turn: bool,  // A piece has entered the spiral this turn (for `Rules::single_entry`)
+    last_move: Option<MoveOutcome>,
+    moves: u32,               // Moves made this game
+    capture_free: u32,        // Moves since the last capture
+    longest_capture_free: u32,
+    theme: Theme,             // Colours and glyphs (kept across resets)
+    locale: Locale,           // Language of messages (kept across resets)
+    focus: Option<(Player, usize, u8)>, // Piece and steps selected from the keyboard (see `a11y::focus_targets`)
+    spiral_cache: RefCell<Option<(SpiralLayout, f64, Vec<SquarePosition>)>>, // Last square positions worked out (see `spiral_positions`)
+}
+
+impl Default for GameState {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 #[wasm_bindgen]
 impl GameState {
     #[wasm_bindgen(constructor)]
     pub fn new() -> GameState {
-        GameState {
-            current_player: Player::Light,
-            dice_value: 0,
-            game_over: false,
-            winner: None,
-            light_pieces: vec![0, 0, 0, 0, 0, 0], // 6 pieces at start (position 0)
-            dark_pieces: vec![0, 0, 0, 0, 0, 0],  // 6 pieces at start (position 0)
-        }
+        GameState::with_setup(GameSetup::default())
+    }
+    
+    /// Start a game from setup options (see `GameSetup`)
+    pub fn from_setup(setup: JsValue) -> Result<GameState, JsValue> {
+        let setup: GameSetup = serde_wasm_bindgen::from_value(setup)?;
+        setup.validate()?;
+        Ok(GameState::with_setup(setup))
     }
     
     #[wasm_bindgen(getter)]
@@ -67,18 +257,83 @@ impl GameState {
     }
     
     #[wasm_bindgen(getter)]
+    /// Total of the dice still to be used this turn (0 = not rolled yet)
     pub fn dice_value(&self) -> u8 {
-        self.dice_value
+        self.dice_pool.iter().sum()
+    }
+    
+    /// Dice still to be used this turn
+    pub fn get_dice(&self) -> JsValue {
+        serde_wasm_bindgen::to_value(&self.dice_pool).unwrap()
     }
     
     #[wasm_bindgen(getter)]
     pub fn game_over(&self) -> bool {
-        self.game_over
+        self.result.is_some()
     }
     
     #[wasm_bindgen(getter)]
     pub fn winner(&self) -> Option<Player> {
-        self.winner
+        match self.result? {
+            GameResult::AllPiecesHome(player) => Some(player),
+            GameResult::Resignation(player) | GameResult::Timeout(player) => self.sole_opponent(player),
+            GameResult::Abandoned | GameResult::Draw => None,
+        }
+    }
+    
+    /// How the game ended, e.g. `{ Resignation: "Dark" }` or `"Draw"` (null while in play)
+    pub fn get_result(&self) -> JsValue {
+        serde_wasm_bindgen::to_value(&self.result).unwrap()
+    }
+    
+    /// Resign the game for `player` (and their team). With more than two sides
+    /// left the others play on without them.
+    pub fn resign(&mut self, player: Player) -> bool {
+        if self.game_over() || !self.is_playing(player) {
+            return false;
+        }
+        self.drop_out(player, GameResult::Resignation(player));
+        true
+    }
+    
+    /// Whether `player` is seated and hasn't resigned or lost on time
+    pub fn is_playing(&self, player: Player) -> bool {
+        self.players.get(player.index()).is_some_and(|record| !record.out)
+    }
+    
+    /// Offer (or accept) a draw. The game is drawn once every side has offered.
+    /// Returns false if the game is over or the setup doesn't allow draws.
+    pub fn offer_draw(&mut self, player: Player) -> bool {
+        if self.game_over() || !self.setup.allow_draws || !self.is_playing(player) {
+            return false;
+        }
+        if !self.draw_offers.contains(&player) {
+            self.draw_offers.push(player);
+        }
+        
+        let agreed = self.active_players()
+            .all(|p| self.draw_offers.iter().any(|&offer| self.setup.same_team(offer, p)));
+        if agreed {
+            self.end_game(GameResult::Draw);
+        }
+        true
+    }
+    
+    /// Withdraw or turn down all pending draw offers
+    pub fn decline_draw(&mut self) {
+        self.draw_offers.clear();
+    }
+    
+    /// Whether `player`'s side has a draw offer on the table
+    pub fn has_offered_draw(&self, player: Player) -> bool {
+        self.draw_offers.iter().any(|&offer| self.setup.same_team(offer, player))
+    }
+    
+    /// End the game without a winner (e.g. a player left)
+    pub fn abandon(&mut self) {
+        if !self.game_over() {
+            self.end_game(GameResult::Abandoned);
+        }
     }
     
     pub fn get_board(&self) -> JsValue {
@@ -86,21 +341,14 @@ impl GameState {
         // Position 0 = start, positions 1-35 = board squares, position 36 = center/end
         let mut board_array = vec![0u8; 36];
         
-        // Mark pieces on the board
-        for &pos in &self.light_pieces {
-            if pos > 0 && pos <= 36 {
-                let board_idx = if pos == 36 { 35 } else { pos - 1 };
-                if board_idx < 36 {
-                    board_array[board_idx] = 1; // LightPiece
-                }
-            }
-        }
-        
-        for &pos in &self.dark_pieces {
-            if pos > 0 && pos <= 36 {
-                let board_idx = if pos == 36 { 35 } else { pos - 1 };
-                if board_idx < 36 {
-                    board_array[board_idx] = 2; // DarkPiece
+        // Mark pieces on the board (1 = Light, 2 = Dark, ... see Player::square_type)
+        for record in &self.players {
+            for &pos in &record.pieces {
+                if pos > 0 && pos <= 36 {
+                    let board_idx = if pos == 36 { 35 } else { pos - 1 };
+                    if board_idx < 36 {
+                        board_array[board_idx] = record.player.square_type();
+                    }
                 }
             }
         }
@@ -109,35 +357,102 @@ impl GameState {
     }
     
     pub fn get_pieces(&self) -> JsValue {
-        // Return piece positions for both players
+        // Return piece positions for every player, keyed by class name ({ light: [...], dark: [...] })
+        let pieces: std::collections::BTreeMap<&str, &Vec<usize>> = self.players.iter()
+            .map(|record| (record.player.class_name(), &record.pieces))
+            .collect();
+        let serializer = serde_wasm_bindgen::Serializer::new().serialize_maps_as_objects(true);
+        pieces.serialize(&serializer).unwrap()
+    }
+    
+    /// Number of players in the game
+    #[wasm_bindgen(getter)]
+    pub fn player_count(&self) -> usize {
+        self.players.len()
+    }
+    
+    /// Render data for every player in turn order (name, colour, symbol, pieces)
+    pub fn get_players(&self) -> JsValue {
         #[derive(Serialize)]
-        struct Pieces {
-            light: Vec<usize>,
-            dark: Vec<usize>,
+        struct PlayerInfo<'a> {
+            player: Player,
+            name: &'a str,
+            class: &'a str,
+            symbol: &'a str,
+            color: &'a str,
+            text_color: &'a str,
+            image: Option<&'a str>,
+            pieces: &'a [usize],
+            is_current: bool,
+            team: Option<usize>,
         }
-        let pieces = Pieces {
-            light: self.light_pieces.clone(),
-            dark: self.dark_pieces.clone(),
-        };
-        serde_wasm_bindgen::to_value(&pieces).unwrap()
+        
+        let players: Vec<PlayerInfo> = self.players.iter().map(|record| PlayerInfo {
+            player: record.player,
+            name: self.locale.player_name(record.player),
+            class: record.player.class_name(),
+            symbol: &self.theme.player(record.player).glyph,
+            color: &self.theme.player(record.player).color,
+            text_color: &self.theme.player(record.player).text_color,
+            image: self.theme.player(record.player).image.as_deref(),
+            pieces: &record.pieces,
+            is_current: record.player == self.current_player,
+            team: self.setup.team_of(record.player),
+        }).collect();
+        
+        serde_wasm_bindgen::to_value(&players).unwrap()
     }
     
     pub fn roll_dice(&mut self) -> u8 {
-        // Mehen uses dice that give values 1-6
-        let random = js_sys::Math::random();
-        self.dice_value = ((random * 6.0).floor() as u8) + 1;
-        self.dice_value
+        if !self.check_clock() {
+            return 0;
+        }
+        self.dice_pool = (0..self.setup.rules.dice_count.max(1)).map(|_| self.throw_die()).collect();
+        self.dice_value()
+    }
+    
+    /// Use seeded dice so the sequence of rolls can be reproduced
+    pub fn set_dice_seed(&mut self, seed: u64) {
+        self.dice = Some(SeededDice::new(seed));
     }
     
     pub fn can_move(&self, piece_index: usize) -> bool {
-        if self.game_over || self.dice_value == 0 {
+        self.can_move_piece(self.current_player, piece_index)
+    }
+    
+    /// Check a move of `owner`'s piece by the current player with any one of
+    /// the dice, or part of one when the roll may be split. Only the current
+    /// player's own pieces can move, unless partners share rolls.
+    pub fn can_move_piece(&self, owner: Player, piece_index: usize) -> bool {
+        self.steps_for_piece(owner, piece_index).is_some()
+    }
+    
+    /// Check a move by the die at `die_index` (see `get_dice`)
+    pub fn can_move_with_die(&self, piece_index: usize, die_index: usize) -> bool {
+        self.dice_pool.get(die_index)
+            .is_some_and(|&steps| self.can_move_piece_by(self.current_player, piece_index, steps))
+    }
+    
+    /// Check a move by `steps` squares, which may be part of the roll when the
+    /// rules allow splitting it
+    pub fn can_move_by(&self, piece_index: usize, steps: u8) -> bool {
+        self.can_move_piece_by(self.current_player, piece_index, steps)
+    }
+    
+    pub fn can_move_piece_by(&self, owner: Player, piece_index: usize, steps: u8) -> bool {
+        // A die must cover the steps (exactly, unless the roll may be split)
+        let Some(die) = self.die_for_steps(steps) else { return false };
+        if self.game_over() || steps == 0 {
             return false;
         }
         
-        let pieces = match self.current_player {
-            Player::Light => &self.light_pieces,
-            Player::Dark => &self.dark_pieces,
-        };
+        if owner != self.current_player
+            && !(self.setup.share_rolls && self.setup.same_team(owner, self.current_player))
+        {
+            return false;
+        }
+        
+        let pieces = self.pieces(owner);
         
         if piece_index >= pieces.len() {
             return false;
@@ -150,227 +465,576 @@ impl GameState {
             return false;
         }
         
-        let new_pos = current_pos + self.dice_value as usize;
-        
-        // Can move if new position is within bounds (0-36)
-        if new_pos > 36 {
+        // Entering the spiral may need a particular roll, and may be limited to one
+        // piece a turn. The entry roll is the die thrown, which can't be split.
+        let die_value = self.dice_pool[die];
+        let entry_roll_ok = self.setup.rules.can_enter(die_value)
+            && (self.setup.rules.entry_rolls.is_empty() || steps == die_value);
+        if current_pos == 0
+            && (!entry_roll_ok || (self.setup.rules.single_entry && self.entered_this_turn))
+        {
             return false;
         }
         
-        // Check if destination is blocked by own piece
+        // Where the piece lands under the end-game rule (exact roll, overshoot or bounce)
+        let Some(new_pos) = self.setup.rules.destination(current_pos, steps as usize) else {
+            return false;
+        };
+        
+        // Check if destination is blocked by own (or a partner's) piece; own pieces may stack
         if new_pos < 36 {
-            let blocking_pieces = match self.current_player {
-                Player::Light => &self.light_pieces,
-                Player::Dark => &self.dark_pieces,
-            };
-            
-            for &pos in blocking_pieces {
-                if pos == new_pos {
+            let stacking = self.setup.rules.stacking != Stacking::Off;
+            for partner in self.players().filter(|&p| self.setup.same_team(p, owner) && !(stacking && p == owner)) {
+                let blocked = self.pieces(partner).iter().enumerate()
+                    .any(|(i, &pos)| pos == new_pos && (partner, i) != (owner, piece_index));
+                if blocked {
                     return false; // Blocked by own piece
                 }
             }
+            
+            // An opponent's piece blocks the square unless it can be captured there
+            if self.opponent_at(owner, new_pos).is_some() && !self.can_capture_at(new_pos) {
+                return false;
+            }
         }
         
         true
     }
     
+    /// Legal moves for each remaining die, partners' pieces included when rolls
+    /// are shared, as `{ player, seat, piece, die_index, die, from, to }` objects.
+    /// Play one with `make_move_piece_with_die`.
     pub fn get_valid_moves(&self) -> JsValue {
-        let mut moves = Vec::new();
-        
-        let pieces = match self.current_player {
-            Player::Light => &self.light_pieces,
-            Player::Dark => &self.dark_pieces,
-        };
-        
-        for i in 0..pieces.len() {
-            if self.can_move(i) {
-                moves.push(i);
-            }
+        #[derive(Serialize)]
+        struct ValidMove {
+            player: Player,
+            seat: usize, // Value of the `Player` enum, for `make_move_piece_with_die`
+            piece: usize,
+            die_index: usize,
+            die: u8,
+            from: usize,
+            to: usize,
         }
         
+        let moves: Vec<ValidMove> = self.die_moves().into_iter()
+            .map(|(owner, piece, die_index)| {
+                let from = self.pieces(owner)[piece];
+                let die = self.dice_pool[die_index];
+                ValidMove {
+                    player: owner,
+                    seat: owner.index(),
+                    piece,
+                    die_index,
+                    die,
+                    from,
+                    to: self.setup.rules.destination(from, die as usize).expect("checked by can_move_piece_by"),
+                }
+            })
+            .collect();
+        
         serde_wasm_bindgen::to_value(&moves).unwrap()
     }
     
     pub fn make_move(&mut self, piece_index: usize) -> bool {
-        if !self.can_move(piece_index) {
+        self.make_move_piece(self.current_player, piece_index)
+    }
+    
+    /// Move one of `owner`'s pieces with the first die that allows it, or as
+    /// far as part of a die allows when the roll may be split (see `can_move_piece`)
+    pub fn make_move_piece(&mut self, owner: Player, piece_index: usize) -> bool {
+        match self.steps_for_piece(owner, piece_index) {
+            Some(steps) => self.make_move_piece_by(owner, piece_index, steps),
+            None => false,
+        }
+    }
+    
+    /// Move a piece by the die at `die_index`, so the dice can be used in either order
+    pub fn make_move_with_die(&mut self, piece_index: usize, die_index: usize) -> bool {
+        self.make_move_piece_with_die(self.current_player, piece_index, die_index)
+    }
+    
+    /// Move one of `owner`'s pieces by the die at `die_index` (see `get_valid_moves`)
+    pub fn make_move_piece_with_die(&mut self, owner: Player, piece_index: usize, die_index: usize) -> bool {
+        match self.dice_pool.get(die_index) {
+            Some(&steps) => self.make_move_piece_by(owner, piece_index, steps),
+            None => false,
+        }
+    }
+    
+    /// Move a piece by part of the roll. The turn carries on until the dice are
+    /// used up or none of them can be used.
+    pub fn make_move_by(&mut self, piece_index: usize, steps: u8) -> bool {
+        self.make_move_piece_by(self.current_player, piece_index, steps)
+    }
+    
+    pub fn make_move_piece_by(&mut self, owner: Player, piece_index: usize, steps: u8) -> bool {
+        if !self.check_clock() || !self.can_move_piece_by(owner, piece_index, steps) {
             return false;
         }
         
-        let pieces = match self.current_player {
-            Player::Light => &mut self.light_pieces,
-            Player::Dark => &mut self.dark_pieces,
-        };
+        let current_pos = self.players[owner.index()].pieces[piece_index];
+        let new_pos = self.setup.rules.destination(current_pos, steps as usize)
+            .expect("checked by can_move_piece_by");
         
-        let current_pos = pieces[piece_index];
-        let new_pos = current_pos + self.dice_value as usize;
+        // Check for capturing an opponent piece (a square holds at most one; partners are never captured)
+        let victim = if new_pos < 36 { self.opponent_at(owner, new_pos) } else { None };
         
-        // Move the piece
-        pieces[piece_index] = new_pos;
+        // Move the piece, or the whole stack it is in
+        let moving: Vec<usize> = if self.setup.rules.stacking == Stacking::Unit && current_pos > 0 {
+            (0..self.pieces(owner).len()).filter(|&i| self.pieces(owner)[i] == current_pos).collect()
+        } else {
+            vec![piece_index]
+        };
+        for &i in &moving {
+            self.players[owner.index()].pieces[i] = new_pos;
+        }
+        if current_pos == 0 {
+            self.entered_this_turn = true;
+        }
         
-        // Check for capturing opponent piece
-        if new_pos < 36 {
-            let opponent_pieces = match self.current_player {
-                Player::Light => &mut self.dark_pieces,
-                Player::Dark => &mut self.light_pieces,
-            };
-            
-            // Send opponent piece back to start if captured
-            for pos in opponent_pieces.iter_mut() {
-                if *pos == new_pos {
-                    *pos = 0; // Send back to start
-                    break;
+        // Send the captured piece back as the capture rule says
+        let captured = victim.map(|(player, piece)| {
+            let to = match self.setup.rules.capture {
+                Capture::PushBack(squares) => self.push_back(new_pos, squares as usize),
+                Capture::PushBackRolled => {
+                    let squares = self.throw_die() as usize;
+                    self.push_back(new_pos, squares)
                 }
+                // Swap only onto an emptied square, so pieces left behind in a stack
+                // never share it with an opponent
+                Capture::Swap if !self.players.iter().any(|record| record.pieces.contains(&current_pos)) => current_pos,
+                Capture::Swap | Capture::ToStart | Capture::None => 0,
+            };
+            self.players[player.index()].pieces[piece] = to;
+            PieceMove { player, piece, from: new_pos, to }
+        });
+        
+        // Keep the capture statistics
+        self.moves += 1;
+        match captured {
+            Some(victim) => {
+                self.players[owner.index()].captures_made += 1;
+                self.players[victim.player.index()].captures_suffered += 1;
+                self.capture_free = 0;
+            }
+            None => {
+                self.capture_free += 1;
+                self.longest_capture_free = self.longest_capture_free.max(self.capture_free);
             }
         }
         
-        self.dice_value = 0;
+        self.last_move = Some(MoveOutcome {
+            moved: PieceMove { player: owner, piece: piece_index, from: current_pos, to: new_pos },
+            stack: moving.len(),
+            captured,
+        });
+        
+        let die = self.die_for_steps(steps).expect("checked by can_move_piece_by");
+        self.dice_pool[die] -= steps;
+        if self.dice_pool[die] == 0 {
+            self.dice_pool.remove(die);
+        }
+        
+        self.draw_offers.clear();
         self.check_win_condition();
-        if !self.game_over {
+        if !self.game_over() && (self.dice_pool.is_empty() || self.partial_moves().is_empty()) {
+            self.dice_pool.clear();
             self.switch_player();
         }
         
         true
     }
     
+    /// Pip counts, piece counts and capture statistics (see `GameStats`)
+    pub fn get_stats(&self) -> JsValue {
+        serde_wasm_bindgen::to_value(&self.stats()).unwrap()
+    }
+    
+    /// What moving a piece would do: destination, capture, pieces left exposed
+    /// to the opponent's next roll, and the change in race lead (null if illegal)
+    pub fn preview_move(&self, piece_index: usize) -> JsValue {
+        serde_wasm_bindgen::to_value(&engine::preview_move(self, piece_index)).unwrap()
+    }
+    
+    /// The last move made and anything it captured (null before the first move)
+    pub fn get_last_move(&self) -> JsValue {
+        serde_wasm_bindgen::to_value(&self.last_move).unwrap()
+    }
+    
+    /// Every legal move with all or part of what is left of the roll
+    pub fn get_partial_moves(&self) -> JsValue {
+        serde_wasm_bindgen::to_value(&self.partial_moves()).unwrap()
+    }
+    
+    /// Moves one of `owner`'s pieces can make, one per number of steps, for
+    /// letting the player pick a destination when there is more than one
+    pub fn get_piece_moves(&self, owner: Player, piece_index: usize) -> JsValue {
+        let moves: Vec<PartialMove> = self.partial_moves().into_iter()
+            .filter(|m| m.player == owner && m.piece == piece_index)
+            .collect();
+        serde_wasm_bindgen::to_value(&moves).unwrap()
+    }
+    
     pub fn pass_turn(&mut self) {
         // Pass turn when no valid moves available
-        if self.dice_value != 0 {
-            self.dice_value = 0;
+        if self.check_clock() && !self.dice_pool.is_empty() {
+            self.dice_pool.clear();
+            self.players[self.current_player.index()].turns_passed += 1;
             self.switch_player();
         }
     }
     
     fn switch_player(&mut self) {
-        self.current_player = match self.current_player {
-            Player::Light => Player::Dark,
-            Player::Dark => Player::Light,
-        };
+        self.current_player = self.next_player(self.current_player);
+        self.entered_this_turn = false;
+        
+        if let Some(clock) = self.clock.as_mut() {
+            let now = self.time_source.now_ms();
+            clock.end_turn(now, true);
+            clock.start_turn(self.current_player.index(), now);
+        }
     }
     
-    fn check_win_condition(&mut self) {
-        // Check if all pieces have reached the center (position 36)
-        let light_won = self.light_pieces.iter().all(|&pos| pos >= 36);
-        let dark_won = self.dark_pieces.iter().all(|&pos| pos >= 36);
-        
-        if light_won {
-            self.game_over = true;
-            self.winner = Some(Player::Light);
-        } else if dark_won {
-            self.game_over = true;
-            self.winner = Some(Player::Dark);
+    /// Check the running clock. Ends the game if the player to move has run out
+    /// of time; returns false once the game has been lost on time.
+    pub fn check_clock(&mut self) -> bool {
+        if matches!(self.result, Some(GameResult::Timeout(_))) {
+            return false;
         }
+        let Some(clock) = self.clock.as_mut() else {
+            return true;
+        };
+        
+        let now = self.time_source.now_ms();
+        let Some(seat) = clock.flagged(now) else {
+            return true;
+        };
+        
+        let player = self.players[seat].player;
+        self.drop_out(player, GameResult::Timeout(player));
+        false
     }
     
-    pub fn reset(&mut self) {
-        *self = GameState::new();
+    /// Player whose time ran out, if the game was lost on time
+    #[wasm_bindgen(getter)]
+    pub fn timed_out(&self) -> Option<Player> {
+        match self.result {
+            Some(GameResult::Timeout(player)) => Some(player),
+            _ => None,
+        }
     }
     
-    pub fn get_spiral_positions(&self, board_size: f64) -> JsValue {
-        let board_radius = board_size / 2.0;
-        let center_x = board_radius;
-        let center_y = board_radius;
-        
-        let outer_radius = board_radius * 0.85;
-        let middle_radius = board_radius * 0.55;
-        let inner_radius = board_radius * 0.25;
-        
-        let mut positions = Vec::new();
-        
-        // Outer ring: squares 1-18 (18 squares, indices 0-17)
-        let outer_ring_count = 18;
-        let outer_angle_step = 360.0 / outer_ring_count as f64;
-        let outer_start_angle = 0.0;
-        
-        for i in 0..outer_ring_count {
-            let angle = (outer_start_angle + i as f64 * outer_angle_step) % 360.0;
-            let rad = angle.to_radians();
-            
-            positions.push(SquarePosition {
-                x: center_x + outer_radius * rad.cos(),
-                y: center_y + outer_radius * rad.sin(),
-                angle,
-                radius: 85.0,
-            });
+    /// Clock display data: `{ enabled, remaining_ms: [per seat], running, delay_remaining_ms }`
+    pub fn get_clock(&self) -> JsValue {
+        #[derive(Serialize)]
+        struct ClockDisplay {
+            enabled: bool,
+            remaining_ms: Vec<f64>,
+            running: Option<Player>,
+            delay_remaining_ms: f64,
         }
         
-        // Middle ring: squares 19-30 (12 squares, indices 18-29)
-        let middle_ring_count = 12;
-        let middle_angle_step = 360.0 / middle_ring_count as f64;
-        let square18_angle = positions[17].angle;
-        let middle_start_angle = (square18_angle + 15.0) % 360.0;
-        
-        for i in 0..middle_ring_count {
-            let angle = (middle_start_angle + i as f64 * middle_angle_step) % 360.0;
-            let rad = angle.to_radians();
-            
-            positions.push(SquarePosition {
-                x: center_x + middle_radius * rad.cos(),
-                y: center_y + middle_radius * rad.sin(),
-                angle,
-                radius: 55.0,
+        let now = self.time_source.now_ms();
+        let display = match &self.clock {
+            Some(clock) => ClockDisplay {
+                enabled: true,
+                remaining_ms: (0..self.players.len()).map(|seat| clock.remaining(seat, now)).collect(),
+                running: clock.running().map(|seat| self.players[seat].player),
+                delay_remaining_ms: clock.delay_remaining(now),
+            },
+            None => ClockDisplay {
+                enabled: false,
+                remaining_ms: Vec::new(),
+                running: None,
+                delay_remaining_ms: 0.0,
+            },
+        };
+        serde_wasm_bindgen::to_value(&display).unwrap()
+    }
+    
+    fn check_win_condition(&mut self) {
+        // Check if all of a side's pieces have reached the center (position 36),
+        // starting with the player who just moved. Partners only win together.
+        let seats = self.players.len();
+        let first = self.current_player.index();
+        let winner = (0..seats)
+            .map(|offset| self.players[(first + offset) % seats].player)
+            .find(|&player| {
+                self.players()
+                    .filter(|&p| self.setup.same_team(p, player))
+                    .all(|p| self.pieces(p).iter().all(|&pos| pos >= 36))
             });
+        
+        if let Some(winner) = winner {
+            self.end_game(GameResult::AllPiecesHome(winner));
+        }
+    }
+    
+    /// Take `player`'s side out of the game after a resignation or timeout. The
+    /// game ends with `result` once a single side is left; until then the others
+    /// play on, and the side's pieces leave the board so they don't block it.
+    fn drop_out(&mut self, player: Player, result: GameResult) {
+        let side: Vec<Player> = self.players().filter(|&p| self.setup.same_team(p, player)).collect();
+        for &p in &side {
+            self.players[p.index()].out = true;
         }
         
-        // Inner ring: squares 31-36 (6 squares, indices 30-35)
-        let inner_ring_count = 6;
-        let inner_angle_step = 360.0 / inner_ring_count as f64;
-        let square30_angle = positions[29].angle;
-        let inner_start_angle = (square30_angle + 30.0) % 360.0;
+        let left: Vec<Player> = self.active_players().collect();
+        let one_side_left = left.iter().all(|&p| self.setup.same_team(p, left[0]));
+        if one_side_left {
+            self.end_game(result);
+            return;
+        }
         
-        for i in 0..inner_ring_count {
-            let angle = (inner_start_angle + i as f64 * inner_angle_step) % 360.0;
-            let rad = angle.to_radians();
-            
-            positions.push(SquarePosition {
-                x: center_x + inner_radius * rad.cos(),
-                y: center_y + inner_radius * rad.sin(),
-                angle,
-                radius: 25.0,
-            });
+        for p in side {
+            for pos in self.players[p.index()].pieces.iter_mut().filter(|pos| **pos < rules::HOME) {
+                *pos = 0;
+            }
+        }
+        self.draw_offers.clear();
+        if !self.is_playing(self.current_player) {
+            self.dice_pool.clear();
+            self.switch_player();
+        }
+    }
+    
+    /// Finish the game: record the result and stop the clock
+    fn end_game(&mut self, result: GameResult) {
+        self.result = Some(result);
+        self.dice_pool.clear();
+        self.draw_offers.clear();
+        if let Some(clock) = self.clock.as_mut() {
+            clock.end_turn(self.time_source.now_ms(), false);
+        }
+    }
+    
+    /// Team of the winner, if the game was played in teams
+    #[wasm_bindgen(getter)]
+    pub fn winning_team(&self) -> Option<usize> {
+        self.winner().and_then(|w| self.setup.team_of(w))
+    }
+    
+    /// Moves the current player can make, including partners' pieces when
+    /// rolls are shared, as `[{ player, piece_index }]`
+    pub fn get_team_moves(&self) -> JsValue {
+        #[derive(Serialize)]
+        struct TeamMove {
+            player: Player,
+            piece_index: usize,
+        }
+        let moves: Vec<TeamMove> = self.team_moves().into_iter()
+            .map(|(player, piece_index)| TeamMove { player, piece_index })
+            .collect();
+        serde_wasm_bindgen::to_value(&moves).unwrap()
+    }
+    
+    /// Start a new game with the same setup
+    pub fn reset(&mut self) {
+        self.reset_with(self.setup.clone());
+    }
+    
+    /// Start a new game with different setup options
+    pub fn reset_with_setup(&mut self, setup: JsValue) -> Result<(), JsValue> {
+        let setup: GameSetup = serde_wasm_bindgen::from_value(setup)?;
+        setup.validate()?;
+        self.reset_with(setup);
+        Ok(())
+    }
+    
+    /// Number of games started before this one (0 for the first game)
+    #[wasm_bindgen(getter)]
+    pub fn game_number(&self) -> u32 {
+        self.game_number
+    }
+    
+    /// Movement rules in play, e.g. `{ bear_off: "Exact" }`
+    pub fn get_rules(&self) -> JsValue {
+        serde_wasm_bindgen::to_value(&self.setup.rules).unwrap()
+    }
+    
+    /// Throws of the roll-off that decided the first player: one array per round, in seat order
+    pub fn get_roll_off(&self) -> JsValue {
+        serde_wasm_bindgen::to_value(&self.roll_off).unwrap()
+    }
+    
+    /// SVG document of the current position (board plus each player's bench)
+    pub fn get_board_svg(&self, board_size: f64) -> String {
+        render::svg::render(self, board_size)
+    }
+    
+    /// Keyframes for tweening the last move: the moved piece along the spiral,
+    /// then any captured piece knocked back (null before the first move)
+    pub fn animation_for_last_move(&self, board_size: f64) -> JsValue {
+        serde_wasm_bindgen::to_value(&render::animation::last_move(self, board_size)).unwrap()
+    }
+    
+    /// What is under a pointer at (x, y) on a board `board_size` across: a square,
+    /// a piece in a start area or the center (null for nothing). Uses the same
+    /// geometry as the renderers, with some slack for touch input.
+    pub fn hit_test(&self, x: f64, y: f64, board_size: f64) -> JsValue {
+        let hit = render::Scene::new(self, board_size).hit_test(x, y, render::TOUCH_TOLERANCE);
+        serde_wasm_bindgen::to_value(&hit).unwrap()
+    }
+    
+    /// Draw commands (circle, arc, path, text) for a canvas, in device pixels
+    pub fn get_draw_commands(&self, board_size: f64, dpr: f64) -> JsValue {
+        serde_wasm_bindgen::to_value(&render::commands::render(self, board_size, dpr)).unwrap()
+    }
+    
+    /// Colours for the style tokens used in draw commands, e.g. `{ "board-fill": "#d4a574" }`
+    pub fn get_palette(&self) -> JsValue {
+        let palette: std::collections::BTreeMap<String, &str> =
+            render::commands::palette(self).into_iter().collect();
+        let serializer = serde_wasm_bindgen::Serializer::new().serialize_maps_as_objects(true);
+        palette.serialize(&serializer).unwrap()
+    }
+    
+    /// Switch to a built-in theme ("classic", "high-contrast" or "colour-blind-safe")
+    pub fn set_theme(&mut self, name: &str) -> bool {
+        match Theme::builtin(name) {
+            Some(theme) => {
+                self.theme = theme;
+                true
+            }
+            None => false,
+        }
+    }
+    
+    /// Use a theme loaded from JSON (see `Theme`)
+    pub fn load_theme(&mut self, json: &str) -> Result<(), JsValue> {
+        self.theme = Theme::from_json(json)?;
+        Ok(())
+    }
+    
+    /// Current theme (player styles, board palette and status colours)
+    pub fn get_theme(&self) -> JsValue {
+        serde_wasm_bindgen::to_value(&self.theme).unwrap()
+    }
+    
+    /// Names of the built-in themes
+    pub fn get_theme_names(&self) -> JsValue {
+        serde_wasm_bindgen::to_value(&theme::BUILTIN_THEMES).unwrap()
+    }
+    
+    /// Switch the language of messages, e.g. "fr" or "de-AT" (false if not supported)
+    pub fn set_locale(&mut self, code: &str) -> bool {
+        match Locale::from_code(code) {
+            Some(locale) => {
+                self.locale = locale;
+                true
+            }
+            None => false,
+        }
+    }
+    
+    pub fn get_locale(&self) -> String {
+        self.locale.code().to_string()
+    }
+    
+    /// Codes of the supported languages
+    pub fn get_locales(&self) -> JsValue {
+        let codes: Vec<&str> = Locale::ALL.iter().map(|locale| locale.code()).collect();
+        serde_wasm_bindgen::to_value(&codes).unwrap()
+    }
+    
+    /// "ltr" or "rtl", for the page's `dir` attribute
+    pub fn get_text_direction(&self) -> String {
+        self.locale.direction().to_string()
+    }
+    
+    /// A message from the catalog in the current language, e.g. "ui.roll_dice"
+    pub fn get_message(&self, key: &str) -> String {
+        self.locale.message(key).to_string()
+    }
+    
+    /// A catalog message with its parameters filled in from an object, e.g.
+    /// `format_message("preview.exposed", { percent: "25" })`
+    pub fn format_message(&self, key: &str, args: JsValue) -> String {
+        let args: std::collections::HashMap<String, String> = serde_wasm_bindgen::from_value(args).unwrap_or_default();
+        let args: Vec<(&str, &str)> = args.iter().map(|(name, value)| (name.as_str(), value.as_str())).collect();
+        self.locale.format(key, &args)
+    }
+    
+    /// The position as one sentence for screen readers (see `a11y::describe_position`)
+    pub fn get_position_description(&self) -> String {
+        a11y::describe_position(self)
+    }
+    
+    /// Spoken announcement of the last move (empty before the first move)
+    pub fn get_last_move_announcement(&self) -> String {
+        match &self.last_move {
+            Some(outcome) => a11y::announce(self, outcome),
+            None => String::new(),
+        }
+    }
+    
+    /// Piece selected from the keyboard, with its spoken description (null when none)
+    pub fn get_focus(&self) -> JsValue {
+        serde_wasm_bindgen::to_value(&self.focused()).unwrap()
+    }
+    
+    /// Keyboard move selection: arrow keys step through the pieces that can
+    /// move, Enter (or Space) plays the selected one and Escape clears it
+    pub fn handle_key(&mut self, key: &str) -> JsValue {
+        #[derive(Serialize)]
+        struct KeyResult {
+            focus: Option<FocusTarget>,
+            moved: bool,
+            announcement: String, // Text for an ARIA live region
         }
         
-        serde_wasm_bindgen::to_value(&positions).unwrap()
+        let mut moved = false;
+        let announcement = match key {
+            "ArrowRight" | "ArrowDown" => self.move_focus(1),
+            "ArrowLeft" | "ArrowUp" => self.move_focus(-1),
+            "Enter" | " " => {
+                moved = self.play_focus();
+                if moved {
+                    self.get_last_move_announcement()
+                } else {
+                    self.move_focus(0)
+                }
+            }
+            "Escape" => {
+                self.focus = None;
+                String::new()
+            }
+            _ => String::new(),
+        };
+        
+        serde_wasm_bindgen::to_value(&KeyResult {
+            focus: self.focused(),
+            moved,
+            announcement,
+        }).unwrap()
+    }
+    
+    pub fn get_spiral_positions(&self, board_size: f64) -> JsValue {
+        serde_wasm_bindgen::to_value(&self.spiral_positions(board_size)).unwrap()
     }
     
     pub fn get_square_data(&self) -> JsValue {
         let board_array = self.get_board();
-        let valid_moves: Vec<usize> = serde_wasm_bindgen::from_value(
-            self.get_valid_moves()
-        ).unwrap_or_default();
-        
-        #[derive(Deserialize)]
-        struct Pieces {
-            light: Vec<usize>,
-            dark: Vec<usize>,
-        }
-        let pieces: Pieces = serde_wasm_bindgen::from_value(self.get_pieces()).unwrap();
-        let player_pieces = match self.current_player {
-            Player::Light => &pieces.light,
-            Player::Dark => &pieces.dark,
-        };
+        let stacks = self.stacks();
+        // Board squares (0-based) holding a piece the current player can move
+        let movable: Vec<usize> = self.team_moves().into_iter()
+            .map(|(owner, piece)| self.pieces(owner)[piece])
+            .filter(|&pos| pos > 0)
+            .map(|pos| pos.min(36) - 1)
+            .collect();
         
         let board: Vec<u8> = serde_wasm_bindgen::from_value(board_array).unwrap_or_default();
         let mut square_data = Vec::new();
         
-        for square_index in 0..36 {
+        for (square_index, &stack) in stacks.iter().enumerate() {
             let square_type = board.get(square_index).copied().unwrap_or(0);
-            let mut is_valid_move = false;
-            
-            if square_type != 0 {
-                for i in 0..player_pieces.len() {
-                    let piece_pos = player_pieces[i];
-                    let board_pos = if piece_pos == 36 { 35 } else if piece_pos > 0 { piece_pos - 1 } else { usize::MAX };
-                    if board_pos == square_index && valid_moves.contains(&i) {
-                        is_valid_move = true;
-                        break;
-                    }
-                }
-            }
+            let is_valid_move = square_type != 0 && movable.contains(&square_index);
             
             let is_center = square_index == 35 && (square_type != 0 || is_valid_move);
             
             square_data.push(SquareData {
                 square_type,
+                stack,
                 is_valid_move,
                 is_center,
             });
@@ -382,21 +1046,14 @@ impl GameState {
     pub fn get_center_pieces(&self) -> JsValue {
         let mut center_pieces = Vec::new();
         
-        for i in 0..self.light_pieces.len() {
-            if self.light_pieces[i] == 36 {
-                center_pieces.push(CenterPiece {
-                    player: "light".to_string(),
-                    index: i,
-                });
-            }
-        }
-        
-        for i in 0..self.dark_pieces.len() {
-            if self.dark_pieces[i] == 36 {
-                center_pieces.push(CenterPiece {
-                    player: "dark".to_string(),
-                    index: i,
-                });
+        for record in &self.players {
+            for (i, &pos) in record.pieces.iter().enumerate() {
+                if pos == 36 {
+                    center_pieces.push(CenterPiece {
+                        player: record.player.class_name().to_string(),
+                        index: i,
+                    });
+                }
             }
         }
         
@@ -404,18 +1061,13 @@ impl GameState {
     }
     
     pub fn get_start_pieces(&self) -> JsValue {
-        let valid_moves: Vec<usize> = serde_wasm_bindgen::from_value(
-            self.get_valid_moves()
-        ).unwrap_or_default();
+        let valid_moves = self.valid_moves();
         
-        let pieces = match self.current_player {
-            Player::Light => &self.light_pieces,
-            Player::Dark => &self.dark_pieces,
-        };
+        let pieces = self.pieces(self.current_player);
         
         let mut start_pieces = Vec::new();
-        for i in 0..pieces.len() {
-            if pieces[i] == 0 {
+        for (i, &pos) in pieces.iter().enumerate() {
+            if pos == 0 {
                 start_pieces.push(StartPiece {
                     index: i,
                     is_valid_move: valid_moves.contains(&i),
@@ -434,11 +1086,12 @@ impl GameState {
             left: f64,
             top: f64,
             inner_html: String,
+            preview: Option<MovePreview>,
+            background: Option<String>,
+            color: Option<String>,
         }
         
-        let spiral_positions: Vec<SquarePosition> = serde_wasm_bindgen::from_value(
-            self.get_spiral_positions(board_size)
-        ).unwrap_or_default();
+        let spiral_positions = self.spiral_positions(board_size);
         
         if square_index >= spiral_positions.len() {
             return serde_wasm_bindgen::to_value(&SquareRenderInfo {
@@ -446,6 +1099,9 @@ impl GameState {
                 left: 0.0,
                 top: 0.0,
                 inner_html: "".to_string(),
+                preview: None,
+                background: None,
+                color: None,
             }).unwrap();
         }
         
@@ -456,7 +1112,9 @@ impl GameState {
         struct SquareRenderData {
             content: String,
             classes: Vec<String>,
-            is_valid_move: bool,
+            preview: Option<MovePreview>,
+            background: Option<String>,
+            color: Option<String>,
         }
         
         let render_data: SquareRenderData = serde_wasm_bindgen::from_value(render_data_value).unwrap();
@@ -469,6 +1127,9 @@ impl GameState {
             left: pos.x - square_size / 2.0,
             top: pos.y - square_size / 2.0,
             inner_html,
+            preview: render_data.preview,
+            background: render_data.background,
+            color: render_data.color,
         }).unwrap()
     }
     
@@ -478,17 +1139,21 @@ impl GameState {
         struct CenterPieceHTML {
             class: String,
             text: String,
+            color: String,
         }
         
         let center_pieces: Vec<CenterPiece> = serde_wasm_bindgen::from_value(
             self.get_center_pieces()
         ).unwrap_or_default();
         
-        let html_data: Vec<CenterPieceHTML> = center_pieces.iter().map(|p| {
-            CenterPieceHTML {
+        let html_data: Vec<CenterPieceHTML> = center_pieces.iter().filter_map(|p| {
+            let player = Player::ALL.iter().find(|player| player.class_name() == p.player)?;
+            let style = self.theme.player(*player);
+            Some(CenterPieceHTML {
                 class: format!("center-piece {}-piece", p.player),
-                text: if p.player == "light" { "○".to_string() } else { "●".to_string() },
-            }
+                text: style.glyph.clone(),
+                color: style.color.clone(),
+            })
         }).collect();
         
         serde_wasm_bindgen::to_value(&html_data).unwrap()
@@ -502,12 +1167,16 @@ impl GameState {
             text: String,
             index: usize,
             is_valid: bool,
+            title: String,
+            preview: Option<MovePreview>,
         }
         
         let start_pieces: Vec<StartPiece> = serde_wasm_bindgen::from_value(
             self.get_start_pieces()
         ).unwrap_or_default();
         
+        let title = self.entry_hint();
+        
         let player_symbol = self.get_current_player_symbol();
         let html_data: Vec<StartPieceHTML> = start_pieces.iter().map(|p| {
             StartPieceHTML {
@@ -515,12 +1184,104 @@ impl GameState {
                 text: player_symbol.clone(),
                 index: p.index,
                 is_valid: p.is_valid_move,
+                title: title.clone(),
+                preview: if p.is_valid_move { engine::preview_move(self, p.index) } else { None },
             }
         }).collect();
         
         serde_wasm_bindgen::to_value(&html_data).unwrap()
     }
     
+    /// Each player's start and home areas, in seat order: the pieces waiting to
+    /// enter and the pieces home, with counts, labels and theme colours. The
+    /// current player's area is marked active.
+    pub fn get_player_areas(&self) -> JsValue {
+        #[derive(Serialize)]
+        struct AreaPiece {
+            index: usize,
+            class: String,
+            is_valid: bool,
+            preview: Option<MovePreview>,
+        }
+        
+        #[derive(Serialize)]
+        struct PlayerArea<'a> {
+            player: Player,
+            seat: usize, // Value of the `Player` enum, for `make_move_piece`
+            name: &'a str,
+            class: String,
+            active: bool,
+            glyph: &'a str,
+            color: &'a str,
+            text_color: &'a str,
+            image: Option<&'a str>,
+            title: &'a str, // Entry rule hint for the waiting pieces
+            waiting: Vec<AreaPiece>,
+            waiting_count: usize,
+            waiting_label: String,
+            home: Vec<AreaPiece>,
+            home_count: usize,
+            home_label: String,
+        }
+        
+        let title = self.entry_hint();
+        let movable = self.team_moves();
+        
+        let areas: Vec<PlayerArea> = self.players().map(|player| {
+            let style = self.theme.player(player);
+            let active = player == self.current_player;
+            let pieces = self.pieces(player);
+            
+            let waiting: Vec<AreaPiece> = pieces.iter().enumerate()
+                .filter(|&(_, &pos)| pos == 0)
+                .map(|(index, _)| {
+                    let is_valid = movable.contains(&(player, index));
+                    AreaPiece {
+                        index,
+                        class: format!("start-piece {}-piece{}", player.class_name(), if is_valid { " valid-move" } else { "" }),
+                        is_valid,
+                        preview: if is_valid { engine::preview_piece_move(self, player, index) } else { None },
+                    }
+                })
+                .collect();
+            let home: Vec<AreaPiece> = pieces.iter().enumerate()
+                .filter(|&(_, &pos)| pos >= rules::HOME)
+                .map(|(index, _)| AreaPiece {
+                    index,
+                    class: format!("home-piece {}-piece", player.class_name()),
+                    is_valid: false,
+                    preview: None,
+                })
+                .collect();
+            
+            PlayerArea {
+                player,
+                seat: player.index(),
+                name: self.locale.player_name(player),
+                class: format!(
+                    "player-area {}-area{}{}",
+                    player.class_name(),
+                    if active { " active" } else { "" },
+                    if self.is_playing(player) { "" } else { " out" },
+                ),
+                active,
+                glyph: &style.glyph,
+                color: &style.color,
+                text_color: &style.text_color,
+                image: style.image.as_deref(),
+                title: &title,
+                waiting_count: waiting.len(),
+                waiting_label: self.locale.format("area.waiting", &[("count", &waiting.len().to_string())]),
+                waiting,
+                home_count: home.len(),
+                home_label: self.locale.format("bench.home", &[("count", &home.len().to_string())]),
+                home,
+            }
+        }).collect();
+        
+        serde_wasm_bindgen::to_value(&areas).unwrap()
+    }
+    
     /// Get status display data (message and color)
     pub fn get_status_display(&self) -> JsValue {
         #[derive(Serialize)]
@@ -530,10 +1291,10 @@ impl GameState {
         }
         
         let message = self.get_status_message();
-        let color = if self.game_over {
-            "#ff6347".to_string() // Error color
+        let color = if self.game_over() {
+            self.theme.status.game_over.clone()
         } else {
-            "#667eea".to_string() // Normal color
+            self.theme.status.playing.clone()
         };
         
         serde_wasm_bindgen::to_value(&StatusDisplay {
@@ -544,17 +1305,18 @@ impl GameState {
     
     /// Get dice value display string
     pub fn get_dice_display(&self) -> String {
-        if self.dice_value == 0 {
+        if self.dice_pool.is_empty() {
             "-".to_string()
         } else {
-            self.dice_value.to_string()
+            let dice: Vec<String> = self.dice_pool.iter().map(|die| die.to_string()).collect();
+            dice.join(self.locale.message("dice.separator"))
         }
     }
     
     /// Get player indicator class
     pub fn get_player_indicator_class(&self) -> String {
         format!("player-indicator {}", 
-            if self.current_player == Player::Dark { "dark" } else { "" })
+            if self.current_player == Player::Light { "" } else { self.current_player.class_name() })
     }
     
     /// Check if roll dice should auto-pass (no valid moves)
@@ -562,98 +1324,90 @@ impl GameState {
         if rolled_value == 0 {
             return false;
         }
-        let valid_moves: Vec<usize> = serde_wasm_bindgen::from_value(
-            self.get_valid_moves()
-        ).unwrap_or_default();
-        valid_moves.is_empty()
+        self.partial_moves().is_empty()
     }
     
     /// Get the current status message for the game
     pub fn get_status_message(&self) -> String {
-        if self.game_over {
-            if let Some(w) = self.winner {
-                let winner_name = match w {
-                    Player::Light => "Light",
-                    Player::Dark => "Dark",
-                };
-                return format!("Game Over! {} Player Wins!", winner_name);
-            }
+        let locale = self.locale;
+        if let Some(result) = self.result {
+            let reason = match result {
+                GameResult::Resignation(player) => Some(locale.format("result.resigned", &[("player", locale.player_name(player))])),
+                GameResult::Timeout(player) => Some(locale.format("result.timeout", &[("player", locale.player_name(player))])),
+                GameResult::Abandoned => return locale.message("game_over.abandoned").to_string(),
+                GameResult::Draw => return locale.message("game_over.draw").to_string(),
+                GameResult::AllPiecesHome(_) => None,
+            };
+            let outcome = match (self.winning_team(), self.winner()) {
+                (Some(team), _) => Some(locale.format("result.team_wins", &[("team", &self.team_name(team))])),
+                (None, Some(w)) => Some(locale.format("result.player_wins", &[("player", locale.player_name(w))])),
+                (None, None) => None,
+            };
+            return match (reason, outcome) {
+                (Some(reason), Some(outcome)) => {
+                    locale.format("game_over.reason_and_outcome", &[("reason", &reason), ("outcome", &outcome)])
+                }
+                (None, Some(outcome)) => locale.format("game_over.outcome", &[("outcome", &outcome)]),
+                (Some(reason), None) => locale.format("game_over.reason", &[("reason", &reason)]),
+                (None, None) => locale.format("game_over.reason", &[("reason", "")]).trim_end().to_string(),
+            };
         }
         
-        if self.dice_value == 0 {
+        if self.dice_pool.is_empty() {
             return String::new();
         }
         
-        let valid_moves: Vec<usize> = serde_wasm_bindgen::from_value(
-            self.get_valid_moves()
-        ).unwrap_or_default();
+        let message = if self.partial_moves().is_empty() {
+            locale.message("status.no_moves")
+        } else {
+            locale.message("status.select_piece")
+        };
         
-        if valid_moves.is_empty() {
-            return "No valid moves. Turn passes.".to_string();
+        // In team games, say whose turn it is and which side they're on
+        match self.setup.team_of(self.current_player) {
+            Some(team) => locale.format("status.team_turn", &[
+                ("player", locale.player_name(self.current_player)),
+                ("team", &(team + 1).to_string()),
+                ("message", message),
+            ]),
+            None => message.to_string(),
         }
-        
-        "Select a piece to move".to_string()
     }
     
     /// Get player name as string
     pub fn get_player_name(&self) -> String {
-        match self.current_player {
-            Player::Light => "Light".to_string(),
-            Player::Dark => "Dark".to_string(),
-        }
+        self.locale.player_name(self.current_player).to_string()
     }
     
-    /// Get square size based on window width (mobile vs desktop)
-    pub fn get_square_size(&self, window_width: f64) -> f64 {
-        if window_width <= 768.0 {
-            45.0
-        } else {
-            55.0
-        }
+    /// Board, square, start area and font sizes for the space the page has for
+    /// the board (CSS pixels). `orientation` is a Screen Orientation type such
+    /// as "portrait-primary"; leave it empty to go by the viewport's shape.
+    pub fn get_layout(&self, width: f64, height: f64, dpr: f64, orientation: &str) -> JsValue {
+        let layout = self.layout(Viewport {
+            width,
+            height,
+            dpr,
+            orientation: Orientation::parse(orientation, width, height),
+        });
+        serde_wasm_bindgen::to_value(&layout).unwrap()
     }
     
     /// Get piece symbol/content for a square type
     pub fn get_piece_symbol(&self, square_type: u8) -> String {
-        match square_type {
-            1 => "○".to_string(), // LightPiece
-            2 => "●".to_string(), // DarkPiece
-            _ => "".to_string(),  // Empty
-        }
+        Player::from_square_type(square_type)
+            .map_or("", |player| &self.theme.player(player).glyph) // Empty squares have no symbol
+            .to_string()
     }
     
     /// Get piece symbol for current player
     pub fn get_current_player_symbol(&self) -> String {
-        match self.current_player {
-            Player::Light => "○".to_string(),
-            Player::Dark => "●".to_string(),
-        }
+        self.theme.player(self.current_player).glyph.clone()
     }
     
-    /// Find which piece index is at a given square index (for square click handling)
+    /// Find which piece index is at a given square index (for square click
+    /// handling). The piece may be a partner's; `handle_square_click` says whose.
     pub fn find_piece_at_square(&self, square_index: usize) -> Option<usize> {
-        if self.game_over || self.dice_value == 0 {
-            return None;
-        }
-        
-        let pieces = match self.current_player {
-            Player::Light => &self.light_pieces,
-            Player::Dark => &self.dark_pieces,
-        };
-        
-        let valid_moves: Vec<usize> = serde_wasm_bindgen::from_value(
-            self.get_valid_moves()
-        ).unwrap_or_default();
-        
-        for i in 0..pieces.len() {
-            let piece_pos = pieces[i];
-            let board_pos = if piece_pos == 36 { 35 } else if piece_pos > 0 { piece_pos - 1 } else { usize::MAX };
-            
-            if board_pos == square_index && valid_moves.contains(&i) {
-                return Some(i);
-            }
-        }
-        
-        None
+        self.move_at_square(square_index).map(|(_, piece)| piece)
     }
     
     /// Get UI state (button disabled state, etc.)
@@ -662,23 +1416,37 @@ impl GameState {
         struct UIState {
             roll_button_disabled: bool,
             player_is_dark: bool,
+            player_class: String,
+            team: Option<usize>,
+            team_name: Option<String>,
+            partners: Vec<Player>,
         }
         
+        let team = self.setup.team_of(self.current_player);
         let state = UIState {
-            roll_button_disabled: self.dice_value != 0 || self.game_over,
+            roll_button_disabled: !self.dice_pool.is_empty() || self.game_over(),
             player_is_dark: self.current_player == Player::Dark,
+            player_class: self.current_player.class_name().to_string(),
+            team,
+            team_name: team.map(|t| self.team_name(t)),
+            partners: self.players()
+                .filter(|&p| p != self.current_player && self.setup.same_team(p, self.current_player))
+                .collect(),
         };
         
         serde_wasm_bindgen::to_value(&state).unwrap()
     }
     
     /// Get comprehensive render data for a square
-    pub fn get_square_render_data(&self, square_index: usize, square_size: f64) -> JsValue {
+    pub fn get_square_render_data(&self, square_index: usize, _square_size: f64) -> JsValue {
         #[derive(Serialize)]
         struct SquareRenderData {
             content: String,
             classes: Vec<String>,
             is_valid_move: bool,
+            preview: Option<MovePreview>, // Hint overlay for the piece that can move from here
+            background: Option<String>,   // Theme colours of the piece on the square
+            color: Option<String>,
         }
         
         let square_data: Vec<SquareData> = serde_wasm_bindgen::from_value(
@@ -690,17 +1458,35 @@ impl GameState {
                 content: "".to_string(),
                 classes: vec![],
                 is_valid_move: false,
+                preview: None,
+                background: None,
+                color: None,
             }).unwrap();
         }
         
         let data = &square_data[square_index];
         let mut classes = vec!["square".to_string(), "spiral-square".to_string()];
-        let content = self.get_piece_symbol(data.square_type);
+        let occupant = Player::from_square_type(data.square_type);
+        let style = occupant.map(|player| self.theme.player(player));
+        let glyph = render::escape(&self.get_piece_symbol(data.square_type));
+        let mut content = match style.and_then(|style| style.image.as_deref()) {
+            Some(image) => format!(
+                "<img class=\"piece-image\" src=\"{}\" alt=\"{}\">",
+                render::escape(image),
+                glyph,
+            ),
+            None => glyph,
+        };
+        
+        // Stack height badge (not on the center, where finished pieces collect)
+        if data.stack > 1 && !data.is_center {
+            content.push_str(&format!("<span class=\"stack-count\">{}</span>", data.stack));
+            classes.push("stacked".to_string());
+        }
         
-        match data.square_type {
-            1 => classes.push("light-piece".to_string()),
-            2 => classes.push("dark-piece".to_string()),
-            _ => classes.push("empty".to_string()),
+        match occupant {
+            Some(player) => classes.push(format!("{}-piece", player.class_name())),
+            None => classes.push("empty".to_string()),
         }
         
         if data.is_valid_move {
@@ -711,16 +1497,31 @@ impl GameState {
             classes.push("center".to_string());
         }
         
+        let preview = self.move_at_square(square_index)
+            .and_then(|(owner, piece)| engine::preview_piece_move(self, owner, piece));
+        
         serde_wasm_bindgen::to_value(&SquareRenderData {
             content,
             classes,
             is_valid_move: data.is_valid_move,
+            preview,
+            background: style.map(|style| style.color.clone()),
+            color: style.map(|style| style.text_color.clone()),
         }).unwrap()
     }
     
-    /// Handle square click - returns piece index if valid move found
-    pub fn handle_square_click(&self, square_index: usize) -> Option<usize> {
-        self.find_piece_at_square(square_index)
+    /// Handle square click - returns `{ seat, piece }` for `make_move_piece`
+    /// if a piece on the square can move, otherwise `null`
+    pub fn handle_square_click(&self, square_index: usize) -> JsValue {
+        #[derive(Serialize)]
+        struct SquareClick {
+            seat: usize,
+            piece: usize,
+        }
+        
+        let click = self.move_at_square(square_index)
+            .map(|(owner, piece)| SquareClick { seat: owner.index(), piece });
+        serde_wasm_bindgen::to_value(&click).unwrap()
     }
 }
 
@@ -733,30 +1534,494 @@ pub fn init() {
 impl GameState {
     /// Check if a specific square index contains a valid move for the current player
     pub fn is_square_valid_move(&self, square_index: usize) -> bool {
-        if self.game_over || self.dice_value == 0 {
-            return false;
-        }
-        
-        let pieces = match self.current_player {
-            Player::Light => &self.light_pieces,
-            Player::Dark => &self.dark_pieces,
+        self.move_at_square(square_index).is_some()
+    }
+}
+
+
+
+impl GameState {
+    /// Start a game from setup options
+    pub fn with_setup(setup: GameSetup) -> GameState {
+        let dice = setup.dice_seed.map(SeededDice::new);
+        GameState::start(setup, dice, 0, Rc::new(SystemClock))
+    }
+    
+    /// Start a new game with different setup options. Unless the seed changes,
+    /// seeded dice carry on from where they were so a whole match stays reproducible.
+    pub fn reset_with(&mut self, setup: GameSetup) {
+        let dice = match setup.dice_seed {
+            Some(seed) if setup.dice_seed != self.setup.dice_seed => Some(SeededDice::new(seed)),
+            _ => self.dice,
+        };
+        let theme = std::mem::take(&mut self.theme);
+        let locale = self.locale;
+        *self = GameState::start(setup, dice, self.game_number + 1, self.time_source.clone());
+        self.theme = theme;
+        self.locale = locale;
+    }
+    
+    pub fn setup(&self) -> &GameSetup {
+        &self.setup
+    }
+    
+    fn start(setup: GameSetup, dice: Option<SeededDice>, game_number: u32, time_source: Rc<dyn TimeSource>) -> GameState {
+        let mut state = GameState {
+            current_player: Player::Light,
+            dice_pool: Vec::new(),
+            result: None,
+            players: Player::ALL[..setup.player_count()].iter()
+                .map(|&player| PlayerRecord::new(player, vec![0, 0, 0, 0, 0, 0])) // 6 pieces at start (position 0)
+                .collect(),
+            dice,
+            setup,
+            game_number,
+            roll_off: Vec::new(),
+            clock: None,
+            time_source,
+            draw_offers: Vec::new(),
+            entered_this_turn: false,
+            last_move: None,
+            moves: 0,
+            capture_free: 0,
+            longest_capture_free: 0,
+            theme: Theme::classic(),
+            locale: Locale::default(),
+            focus: None,
+            spiral_cache: RefCell::default(),
         };
+        state.current_player = state.choose_starting_player();
         
-        let valid_moves: Vec<usize> = serde_wasm_bindgen::from_value(
-            self.get_valid_moves()
-        ).unwrap_or_default();
+        if let Some(control) = state.setup.time_control {
+            let mut clock = GameClock::new(control, state.players.len());
+            clock.start_turn(state.current_player.index(), state.time_source.now_ms());
+            state.clock = Some(clock);
+        }
+        state
+    }
+    
+    fn choose_starting_player(&mut self) -> Player {
+        let seats = self.players.len();
+        let seat = match self.setup.starting_player {
+            StartingPlayer::Light => 0,
+            StartingPlayer::Dark => 1,
+            StartingPlayer::Random => {
+                ((self.random_unit() * seats as f64) as usize).min(seats - 1)
+            }
+            // Everyone throws; a single highest throw starts, otherwise everyone throws again
+            StartingPlayer::RollOff => loop {
+                let throws: Vec<u8> = (0..seats).map(|_| self.throw_die()).collect();
+                let highest = *throws.iter().max().unwrap();
+                let leaders: Vec<usize> = (0..seats).filter(|&i| throws[i] == highest).collect();
+                self.roll_off.push(throws);
+                if let [leader] = leaders[..] {
+                    break leader;
+                }
+            },
+            StartingPlayer::Alternate => self.game_number as usize % seats,
+        };
+        self.players[seat].player
+    }
+    
+    /// Build a game from an explicit position (used by puzzles and analysis).
+    /// `pieces` holds each player's positions in seat order (Light, Dark, ...), using
+    /// the same encoding as the game: 0 = start, 1-35 = board, 36 = center.
+    pub fn from_position(
+        pieces: Vec<Vec<usize>>,
+        current_player: Player,
+        dice_value: u8,
+    ) -> Result<GameState, String> {
+        if dice_value > 6 {
+            return Err(format!("Dice value {} is out of range", dice_value));
+        }
+        if pieces.len() < 2 || pieces.len() > MAX_PLAYERS {
+            return Err(format!("A game needs 2 to {} players, not {}", MAX_PLAYERS, pieces.len()));
+        }
+        if current_player.index() >= pieces.len() {
+            return Err(format!("{} is not playing", current_player.name()));
+        }
         
-        for i in 0..pieces.len() {
-            if valid_moves.contains(&i) {
-                let piece_pos = pieces[i];
-                let board_pos = if piece_pos == 36 { 35 } else if piece_pos > 0 { piece_pos - 1 } else { usize::MAX };
-                if board_pos == square_index {
-                    return true;
+        let mut occupied: Vec<(usize, Player)> = Vec::new();
+        for (seat, positions) in pieces.iter().enumerate() {
+            let name = Player::ALL[seat].name();
+            if positions.is_empty() {
+                return Err(format!("No {} pieces given", name));
+            }
+            for (i, &pos) in positions.iter().enumerate() {
+                if pos > 36 {
+                    return Err(format!("{} piece {} is off the board ({})", name, i, pos));
+                }
+                // Pieces at the start or in the center may share; board squares hold one piece
+                if pos > 0 && pos < 36 {
+                    if let Some(&(_, other)) = occupied.iter().find(|&&(square, _)| square == pos) {
+                        return Err(format!("{} and {} pieces both on square {}", other.name(), name, pos));
+                    }
+                    occupied.push((pos, Player::ALL[seat]));
                 }
             }
         }
         
-        false
+        let setup = GameSetup { players: pieces.len(), ..GameSetup::default() };
+        let mut state = GameState {
+            current_player,
+            dice_pool: if dice_value == 0 { Vec::new() } else { vec![dice_value] },
+            result: None,
+            players: pieces.into_iter().enumerate()
+                .map(|(seat, pieces)| PlayerRecord::new(Player::ALL[seat], pieces))
+                .collect(),
+            dice: None,
+            setup,
+            game_number: 0,
+            roll_off: Vec::new(),
+            clock: None,
+            time_source: Rc::new(SystemClock),
+            draw_offers: Vec::new(),
+            entered_this_turn: false,
+            last_move: None,
+            moves: 0,
+            capture_free: 0,
+            longest_capture_free: 0,
+            theme: Theme::classic(),
+            locale: Locale::default(),
+            focus: None,
+            spiral_cache: RefCell::default(),
+        };
+        state.check_win_condition();
+        Ok(state)
+    }
+    
+    /// Piece positions for a player (empty if they aren't playing)
+    pub fn pieces(&self, player: Player) -> &[usize] {
+        self.players.get(player.index()).map_or(&[], |record| &record.pieces)
+    }
+    
+    /// Players in turn order
+    pub fn players(&self) -> impl Iterator<Item = Player> + '_ {
+        self.players.iter().map(|record| record.player)
+    }
+    
+    /// Squares evenly spaced along the spiral on a board `board_size` across (see
+    /// `SpiralLayout::positions`). Measuring the curve is slow and a render asks
+    /// for every square, so the last result is kept until the size or shape changes.
+    pub fn spiral_positions(&self, board_size: f64) -> Vec<SquarePosition> {
+        let mut cache = self.spiral_cache.borrow_mut();
+        match cache.as_ref() {
+            Some((layout, size, positions)) if *layout == self.setup.layout && *size == board_size => positions.clone(),
+            _ => {
+                let positions = self.setup.layout.positions(board_size);
+                *cache = Some((self.setup.layout, board_size, positions.clone()));
+                positions
+            }
+        }
+    }
+    
+    /// Players still in the game, in turn order
+    pub fn active_players(&self) -> impl Iterator<Item = Player> + '_ {
+        self.players.iter().filter(|record| !record.out).map(|record| record.player)
+    }
+    
+    /// The player whose turn follows `player`'s, skipping anyone who has dropped out
+    pub fn next_player(&self, player: Player) -> Player {
+        let seats = self.players.len();
+        (1..=seats)
+            .map(|offset| &self.players[(player.index() + offset) % seats])
+            .find(|record| !record.out)
+            .map_or(player, |record| record.player)
+    }
+    
+    /// Random value in [0, 1) from the seeded dice, or `Math.random()` when unseeded
+    fn random_unit(&mut self) -> f64 {
+        match self.dice.as_mut() {
+            Some(dice) => dice.next_f64(),
+            None => js_sys::Math::random(),
+        }
+    }
+    
+    /// A single die throw (1-6)
+    fn throw_die(&mut self) -> u8 {
+        // Mehen uses dice that give values 1-6
+        let random = self.random_unit();
+        ((random * 6.0).floor() as u8) + 1
+    }
+    
+    /// Read time from `source` instead of the system clock. The running turn
+    /// restarts from the new source's current time.
+    pub fn set_time_source(&mut self, source: Rc<dyn TimeSource>) {
+        self.time_source = source;
+        if let Some(clock) = self.clock.as_mut() {
+            clock.restamp(self.time_source.now_ms());
+        }
+    }
+    
+    pub fn theme(&self) -> &Theme {
+        &self.theme
+    }
+    
+    /// Sizes for the HTML front end (see `Layout::solve`), with a start area
+    /// for every player that holds all of their pieces
+    pub fn layout(&self, viewport: Viewport) -> Layout {
+        let pieces = self.players().map(|p| self.pieces(p).len()).max().unwrap_or(0);
+        Layout::solve(viewport, |board_size| self.spiral_positions(board_size), self.player_count(), pieces)
+    }
+    
+    /// The keyboard selection, if it is still a move that can be played
+    pub fn focused(&self) -> Option<FocusTarget> {
+        let (player, piece, steps) = self.focus?;
+        let from = *self.pieces(player).get(piece)?;
+        a11y::focus_targets(self).into_iter()
+            .find(|target| target.player == player && target.from == from && target.steps == steps)
+    }
+    
+    /// Step the keyboard selection `offset` moves along (wrapping round) and
+    /// describe the newly selected piece. With no selection, forward steps
+    /// start from the first piece and backward steps from the last.
+    pub fn move_focus(&mut self, offset: isize) -> String {
+        let targets = a11y::focus_targets(self);
+        if targets.is_empty() {
+            self.focus = None;
+            return self.locale.message("a11y.no_targets").to_string();
+        }
+        let current = self.focused()
+            .and_then(|focused| targets.iter().position(|target| *target == focused));
+        let index = match current {
+            Some(index) => (index as isize + offset).rem_euclid(targets.len() as isize) as usize,
+            None if offset < 0 => targets.len() - 1,
+            None => 0,
+        };
+        let target = &targets[index];
+        self.focus = Some((target.player, target.piece, target.steps));
+        target.description.clone()
+    }
+    
+    /// Play the move selected from the keyboard
+    pub fn play_focus(&mut self) -> bool {
+        let Some(target) = self.focused() else { return false };
+        let moved = self.make_move_piece_by(target.player, target.piece, target.steps);
+        if moved {
+            self.focus = None;
+        }
+        moved
+    }
+    
+    pub fn locale(&self) -> Locale {
+        self.locale
+    }
+    
+    pub fn set_language(&mut self, locale: Locale) {
+        self.locale = locale;
+    }
+    
+    pub fn apply_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+    }
+    
+    /// Statistics for the game so far
+    pub fn stats(&self) -> GameStats {
+        GameStats {
+            players: self.players.iter().map(|record| PlayerStats {
+                player: record.player,
+                pips: record.pieces.iter().map(|&pos| 36 - pos.min(36)).sum(),
+                at_start: record.pieces.iter().filter(|&&pos| pos == 0).count(),
+                on_board: record.pieces.iter().filter(|&&pos| pos > 0 && pos < 36).count(),
+                home: record.pieces.iter().filter(|&&pos| pos >= 36).count(),
+                captures_made: record.captures_made,
+                captures_suffered: record.captures_suffered,
+                turns_passed: record.turns_passed,
+            }).collect(),
+            moves: self.moves,
+            longest_capture_free: self.longest_capture_free,
+        }
+    }
+    
+    pub fn last_move(&self) -> Option<MoveOutcome> {
+        self.last_move
+    }
+    
+    /// Dice still to be used this turn
+    pub fn dice(&self) -> &[u8] {
+        &self.dice_pool
+    }
+    
+    pub fn rules(&self) -> &Rules {
+        &self.setup.rules
+    }
+    
+    /// How the game ended (None while in play)
+    pub fn result(&self) -> Option<GameResult> {
+        self.result
+    }
+    
+    pub fn clock(&self) -> Option<&GameClock> {
+        self.clock.as_ref()
+    }
+    
+    /// Current time from the game's time source
+    pub fn now_ms(&self) -> f64 {
+        self.time_source.now_ms()
+    }
+    
+    /// The one player who wins if `player` drops out: the next opponent in turn
+    /// order, provided every opponent still playing is on the same side
+    fn sole_opponent(&self, player: Player) -> Option<Player> {
+        let mut opponents = self.active_players().filter(|&p| !self.setup.same_team(p, player));
+        let first = opponents.next()?;
+        opponents.all(|p| self.setup.same_team(p, first)).then(|| {
+            let mut next = self.next_player(player);
+            while self.setup.same_team(next, player) {
+                next = self.next_player(next);
+            }
+            next
+        })
+    }
+    
+    /// Team name with its members, e.g. "Team 1 (Light & Red)"
+    pub fn team_name(&self, team: usize) -> String {
+        let members: Vec<&str> = self.players()
+            .filter(|&p| self.setup.team_of(p) == Some(team))
+            .map(|p| self.locale.player_name(p))
+            .collect();
+        self.locale.format("team.name", &[("number", &(team + 1).to_string()), ("members", &members.join(self.locale.message("team.separator")))])
+    }
+    
+    /// Every move available to the current player as `(owner, piece_index)`,
+    /// including partners' pieces when rolls are shared
+    pub fn team_moves(&self) -> Vec<(Player, usize)> {
+        self.players()
+            .flat_map(|owner| (0..self.pieces(owner).len()).map(move |i| (owner, i)))
+            .filter(|&(owner, i)| self.can_move_piece(owner, i))
+            .collect()
+    }
+    
+    /// Tooltip for pieces waiting at the start explaining the entry rule, e.g.
+    /// "Enters on a roll of 1 or 6" (empty when any roll enters)
+    pub fn entry_hint(&self) -> String {
+        let rolls: Vec<String> = self.setup.rules.entry_rolls.iter().map(|roll| roll.to_string()).collect();
+        if rolls.is_empty() {
+            return String::new();
+        }
+        self.locale.format("entry.hint", &[("rolls", &rolls.join(self.locale.message("entry.or")))])
+    }
+    
+    /// Every move with a whole die as `(owner, piece_index, die_index)`, in dice
+    /// order. Dice showing the same number give the same moves, so only the
+    /// first of them is listed.
+    pub fn die_moves(&self) -> Vec<(Player, usize, usize)> {
+        let dice = &self.dice_pool;
+        (0..dice.len())
+            .filter(|&die| !dice[..die].contains(&dice[die]))
+            .flat_map(|die| {
+                self.players().flat_map(move |owner| {
+                    (0..self.pieces(owner).len()).map(move |piece| (owner, piece, die))
+                })
+            })
+            .filter(|&(owner, piece, die)| self.can_move_piece_by(owner, piece, dice[die]))
+            .collect()
+    }
+    
+    /// Movable piece (owner and index) on board square `square_index` (0-based),
+    /// the current player's own or a partner's
+    pub fn move_at_square(&self, square_index: usize) -> Option<(Player, usize)> {
+        if self.game_over() || self.dice_pool.is_empty() {
+            return None;
+        }
+        self.team_moves().into_iter().find(|&(owner, piece)| {
+            let pos = self.pieces(owner)[piece];
+            pos > 0 && pos.min(36) - 1 == square_index
+        })
+    }
+    
+    /// Legal moves with all or part of a die, smallest steps first. Without
+    /// `Rules::split_roll` each move uses a whole die.
+    pub fn partial_moves(&self) -> Vec<PartialMove> {
+        let mut step_counts: Vec<u8> = if self.setup.rules.split_roll {
+            (1..=self.dice_pool.iter().copied().max().unwrap_or(0)).collect()
+        } else {
+            self.dice_pool.clone()
+        };
+        step_counts.sort_unstable();
+        step_counts.dedup();
+        
+        step_counts.into_iter()
+            .flat_map(|steps| {
+                self.players().flat_map(move |player| {
+                    (0..self.pieces(player).len()).map(move |piece| (player, piece, steps))
+                })
+            })
+            .filter(|&(player, piece, steps)| self.can_move_piece_by(player, piece, steps))
+            .map(|(player, piece, steps)| PartialMove {
+                player,
+                piece,
+                steps,
+                to: self.setup.rules
+                    .destination(self.pieces(player)[piece], steps as usize)
+                    .expect("checked by can_move_piece_by"),
+            })
+            .collect()
+    }
+    
+    /// Die that pays for a move of `steps`: one showing exactly that, otherwise
+    /// (when the roll may be split) the smallest die showing more
+    fn die_for_steps(&self, steps: u8) -> Option<usize> {
+        let exact = self.dice_pool.iter().position(|&die| die == steps);
+        if exact.is_some() || !self.setup.rules.split_roll {
+            return exact;
+        }
+        (0..self.dice_pool.len())
+            .filter(|&i| self.dice_pool[i] > steps)
+            .min_by_key(|&i| self.dice_pool[i])
+    }
+    
+    /// Pieces on each of the 36 board squares (square 36 holds every finished piece)
+    pub fn stacks(&self) -> Vec<usize> {
+        let mut stacks = vec![0; 36];
+        for record in &self.players {
+            for &pos in record.pieces.iter().filter(|&&pos| pos > 0) {
+                stacks[pos.min(36) - 1] += 1;
+            }
+        }
+        stacks
+    }
+    
+    /// Whether a piece on a board square can be captured: the rules allow it
+    /// there and it isn't part of a stack
+    pub fn can_capture_at(&self, square: usize) -> bool {
+        self.setup.rules.can_capture_on(square) && self.stacks()[square - 1] < 2
+    }
+    
+    /// Opponent's piece (player and index) on a board square, if any
+    fn opponent_at(&self, owner: Player, square: usize) -> Option<(Player, usize)> {
+        self.players()
+            .filter(|&p| !self.setup.same_team(p, owner))
+            .find_map(|p| self.pieces(p).iter().position(|&pos| pos == square).map(|i| (p, i)))
+    }
+    
+    /// Square a piece captured on `square` is pushed back to: `squares` back,
+    /// or further back to the first free square (the start if there is none)
+    fn push_back(&self, square: usize, squares: usize) -> usize {
+        let mut to = square.saturating_sub(squares);
+        while to > 0 && self.players.iter().any(|record| record.pieces.contains(&to)) {
+            to -= 1;
+        }
+        to
+    }
+    
+    /// Steps one of `owner`'s pieces moves when played without choosing: the
+    /// first die that can move it, otherwise (when the roll may be split) the
+    /// most steps part of a die allows
+    fn steps_for_piece(&self, owner: Player, piece_index: usize) -> Option<u8> {
+        let whole = self.dice_pool.iter().copied()
+            .find(|&die| self.can_move_piece_by(owner, piece_index, die));
+        if whole.is_some() || !self.setup.rules.split_roll {
+            return whole;
+        }
+        let largest = self.dice_pool.iter().copied().max().unwrap_or(0);
+        (1..largest).rev().find(|&steps| self.can_move_piece_by(owner, piece_index, steps))
+    }
+    
+    /// Indices of the current player's pieces that can move with the current roll
+    pub fn valid_moves(&self) -> Vec<usize> {
+        (0..self.pieces(self.current_player).len())
+            .filter(|&i| self.can_move(i))
+            .collect()
     }
 }
-
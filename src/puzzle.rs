@@ -0,0 +1,329 @@
+use wasm_bindgen::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{engine, GameState, Player};
+
+/// Built-in puzzle library
+const BUILTIN_PUZZLES: &str = include_str!("../data/puzzles.json");
+
+/// Rating a new solver starts with
+const START_RATING: f64 = 1200.0;
+
+/// How far a single answer can move the solver's rating
+const RATING_K: f64 = 32.0;
+
+/// Two moves whose evaluations differ by less than this are equally good
+const SCORE_EPSILON: f64 = 1e-9;
+
+/// A "find the best move" position with a fixed roll
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Puzzle {
+    pub id: String,
+    pub title: String,
+    /// Difficulty rating, on the same scale as the solver's rating
+    pub rating: u32,
+    pub to_move: Player,
+    pub roll: u8,
+    pub light: Vec<usize>,
+    pub dark: Vec<usize>,
+    /// Index of the piece that should be moved
+    pub best_move: usize,
+    #[serde(default)]
+    pub hint: String,
+}
+
+impl Puzzle {
+    /// The puzzle position, ready for the player to move
+    pub fn game(&self) -> Result<GameState, String> {
+        GameState::from_position(vec![self.light.clone(), self.dark.clone()], self.to_move, self.roll)
+    }
+}
+
+/// Outcome of answering a puzzle
+#[derive(Debug, Clone, Serialize)]
+pub struct PuzzleResult {
+    pub correct: bool,
+    /// Piece the puzzle expects
+    pub best_move: usize,
+    /// Piece the engine prefers (may differ from `best_move` when moves are equal)
+    pub engine_move: Option<usize>,
+    /// Engine evaluation of the submitted move, `None` if it was illegal
+    pub score: Option<f64>,
+    pub best_score: f64,
+    pub rating_change: f64,
+    pub rating: f64,
+    pub streak: u32,
+}
+
+/// Puzzle library plus the solver's rating and streak
+#[derive(Debug, Clone)]
+#[wasm_bindgen]
+pub struct PuzzleSet {
+    puzzles: Vec<Puzzle>,
+    current: usize,
+    answered: bool,
+    rating: f64,
+    streak: u32,
+    best_streak: u32,
+    solved: u32,
+    attempted: u32,
+}
+
+impl Default for PuzzleSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[wasm_bindgen]
+impl PuzzleSet {
+    /// Load the built-in puzzle library
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> PuzzleSet {
+        PuzzleSet::from_json(BUILTIN_PUZZLES).expect("built-in puzzles are valid")
+    }
+
+    /// Load a puzzle library from JSON (an array of puzzles)
+    pub fn from_json(json: &str) -> Result<PuzzleSet, String> {
+        let puzzles: Vec<Puzzle> = serde_json::from_str(json).map_err(|e| e.to_string())?;
+        if puzzles.is_empty() {
+            return Err("Puzzle library is empty".to_string());
+        }
+
+        for puzzle in &puzzles {
+            let game = puzzle.game().map_err(|e| format!("Puzzle {}: {}", puzzle.id, e))?;
+            if !game.can_move(puzzle.best_move) {
+                return Err(format!("Puzzle {}: best move {} is not legal", puzzle.id, puzzle.best_move));
+            }
+        }
+
+        Ok(PuzzleSet {
+            puzzles,
+            current: 0,
+            answered: false,
+            rating: START_RATING,
+            streak: 0,
+            best_streak: 0,
+            solved: 0,
+            attempted: 0,
+        })
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn len(&self) -> usize {
+        self.puzzles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.puzzles.is_empty()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn current_index(&self) -> usize {
+        self.current
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn rating(&self) -> f64 {
+        self.rating
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn streak(&self) -> u32 {
+        self.streak
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn best_streak(&self) -> u32 {
+        self.best_streak
+    }
+
+    /// Jump to a puzzle by index
+    pub fn select(&mut self, index: usize) -> bool {
+        if index >= self.puzzles.len() {
+            return false;
+        }
+        self.current = index;
+        self.answered = false;
+        true
+    }
+
+    /// Move on to the next puzzle, wrapping around at the end of the library
+    pub fn next_puzzle(&mut self) {
+        let next = (self.current + 1) % self.puzzles.len();
+        self.select(next);
+    }
+
+    /// Game state for the current puzzle
+    pub fn current_game(&self) -> GameState {
+        self.current_puzzle().game().expect("puzzles are validated on load")
+    }
+
+    /// Current puzzle info (without the answer)
+    pub fn get_current_puzzle(&self) -> JsValue {
+        #[derive(Serialize)]
+        struct PuzzleInfo<'a> {
+            id: &'a str,
+            title: &'a str,
+            rating: u32,
+            to_move: Player,
+            roll: u8,
+            hint: &'a str,
+            index: usize,
+            total: usize,
+        }
+
+        let puzzle = self.current_puzzle();
+        serde_wasm_bindgen::to_value(&PuzzleInfo {
+            id: &puzzle.id,
+            title: &puzzle.title,
+            rating: puzzle.rating,
+            to_move: puzzle.to_move,
+            roll: puzzle.roll,
+            hint: &puzzle.hint,
+            index: self.current,
+            total: self.puzzles.len(),
+        }).unwrap()
+    }
+
+    /// Submit an answer for the current puzzle
+    pub fn submit_answer(&mut self, piece_index: usize) -> JsValue {
+        let result = self.check_answer(piece_index);
+        serde_wasm_bindgen::to_value(&result).unwrap()
+    }
+
+    /// Solver statistics
+    pub fn get_stats(&self) -> JsValue {
+        #[derive(Serialize)]
+        struct PuzzleStats {
+            rating: f64,
+            streak: u32,
+            best_streak: u32,
+            solved: u32,
+            attempted: u32,
+        }
+
+        serde_wasm_bindgen::to_value(&PuzzleStats {
+            rating: self.rating,
+            streak: self.streak,
+            best_streak: self.best_streak,
+            solved: self.solved,
+            attempted: self.attempted,
+        }).unwrap()
+    }
+}
+
+impl PuzzleSet {
+    pub fn puzzles(&self) -> &[Puzzle] {
+        &self.puzzles
+    }
+
+    pub fn current_puzzle(&self) -> &Puzzle {
+        &self.puzzles[self.current]
+    }
+
+    /// Check an answer against the engine and update rating and streak.
+    ///
+    /// Any move the engine rates as highly as the puzzle's answer is accepted.
+    /// Only the first answer to a puzzle counts towards rating and streak.
+    pub fn check_answer(&mut self, piece_index: usize) -> PuzzleResult {
+        let puzzle = self.current_puzzle();
+        let game = self.current_game();
+        let best_move = puzzle.best_move;
+        let puzzle_rating = puzzle.rating as f64;
+
+        let best_score = engine::evaluate_move(&game, best_move).expect("best move is legal");
+        let score = engine::evaluate_move(&game, piece_index);
+        let correct = score.is_some_and(|s| s >= best_score - SCORE_EPSILON);
+
+        let mut rating_change = 0.0;
+        if !self.answered {
+            self.answered = true;
+            self.attempted += 1;
+
+            let expected = 1.0 / (1.0 + 10f64.powf((puzzle_rating - self.rating) / 400.0));
+            let actual = if correct { 1.0 } else { 0.0 };
+            rating_change = RATING_K * (actual - expected);
+            self.rating += rating_change;
+
+            if correct {
+                self.solved += 1;
+                self.streak += 1;
+                self.best_streak = self.best_streak.max(self.streak);
+            } else {
+                self.streak = 0;
+            }
+        }
+
+        PuzzleResult {
+            correct,
+            best_move,
+            engine_move: engine::best_move(&game),
+            score,
+            best_score,
+            rating_change,
+            rating: self.rating,
+            streak: self.streak,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn puzzle_json(light: &str, roll: u8, best_move: usize) -> String {
+        format!(
+            r#"[{{"id": "t", "title": "T", "rating": 1200, "to_move": "Light", "roll": {roll}, "light": {light}, "dark": [20], "best_move": {best_move}}}]"#
+        )
+    }
+
+    #[test]
+    fn builtin_answers_agree_with_the_engine() {
+        let set = PuzzleSet::new();
+        for puzzle in set.puzzles() {
+            let game = puzzle.game().unwrap();
+            let engine_move = engine::best_move(&game).unwrap();
+            let engine_score = engine::evaluate_move(&game, engine_move).unwrap();
+            let answer_score = engine::evaluate_move(&game, puzzle.best_move).unwrap();
+            assert!((engine_score - answer_score).abs() < SCORE_EPSILON, "puzzle {}", puzzle.id);
+        }
+    }
+
+    #[test]
+    fn illegal_answers_are_rejected_on_load() {
+        let error = PuzzleSet::from_json(&puzzle_json("[33]", 5, 0)).unwrap_err();
+        assert!(error.contains("not legal"), "{}", error);
+    }
+
+    #[test]
+    fn equally_good_moves_are_accepted() {
+        let mut set = PuzzleSet::from_json(&puzzle_json("[0, 0]", 3, 0)).unwrap();
+        assert!(set.check_answer(1).correct);
+    }
+
+    #[test]
+    fn only_the_first_answer_moves_rating_and_streak() {
+        let mut set = PuzzleSet::new();
+        let correct = |set: &PuzzleSet| set.current_puzzle().best_move;
+
+        let first = set.check_answer(correct(&set));
+        assert!(first.correct && first.rating_change > 0.0);
+        assert_eq!(set.streak(), 1);
+        let again = set.check_answer(99);
+        assert!(!again.correct);
+        assert_eq!(again.rating_change, 0.0);
+        assert_eq!((set.rating(), set.streak()), (first.rating, 1));
+
+        set.next_puzzle();
+        set.check_answer(correct(&set));
+        assert_eq!(set.streak(), 2);
+
+        set.next_puzzle();
+        let wrong = set.check_answer(99);
+        assert!(wrong.rating_change < 0.0);
+        assert_eq!((set.streak(), set.best_streak()), (0, 2));
+        set.check_answer(correct(&set));
+        assert_eq!(set.streak(), 0);
+    }
+}
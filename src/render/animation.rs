@@ -0,0 +1,112 @@
+//! Tween paths for the last move, so front ends can slide pieces along the
+//! spiral instead of jumping them.
+
+use serde::Serialize;
+
+use super::Scene;
+use crate::{GameState, PieceMove};
+
+/// Time a piece takes to move one square
+pub const STEP_MS: f64 = 120.0;
+
+/// Time a captured piece takes to fly back to the start area
+pub const KNOCK_BACK_MS: f64 = 300.0;
+
+/// Where a piece is at a moment of its animation
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct Keyframe {
+    /// Milliseconds from the start of the move
+    pub t: f64,
+    pub x: f64,
+    pub y: f64,
+}
+
+/// Path of one piece, to be interpolated between keyframes
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PieceAnimation {
+    #[serde(flatten)]
+    pub piece: PieceMove,
+    pub keyframes: Vec<Keyframe>,
+}
+
+/// Animations for the last move: the piece that moved, then the one it captured
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MoveAnimation {
+    pub moved: PieceAnimation,
+    pub captured: Option<PieceAnimation>,
+    /// Length of the whole animation
+    pub duration_ms: f64,
+}
+
+/// Coordinates of a position: a bench slot at the start, a square on the
+/// spiral, or the head once home (`None` for a square the board doesn't have)
+fn point(scene: &Scene, piece: &PieceMove, position: usize) -> Option<(f64, f64)> {
+    match position {
+        0 => {
+            let bench = scene.benches.iter().find(|b| b.player == piece.player);
+            Some(match bench {
+                Some(bench) => {
+                    let slot = bench.waiting.iter().position(|&i| i == piece.piece).unwrap_or(bench.waiting.len());
+                    bench.piece_center(slot)
+                }
+                None => scene.center(),
+            })
+        }
+        36.. => Some(scene.center()),
+        square => scene.squares.get(square - 1).map(|square| (square.position.x, square.position.y)),
+    }
+}
+
+/// Squares a piece passes through, in order. A piece that ends up behind
+/// where it started bounced off the head (moving) or was knocked back.
+fn route(from: usize, to: usize, forward: bool) -> Vec<usize> {
+    if to >= from {
+        (from..=to).collect()
+    } else if forward {
+        (from..=36).chain((to..36).rev()).collect()
+    } else {
+        (to..=from).rev().collect()
+    }
+}
+
+fn tween(scene: &Scene, piece: PieceMove, route: &[usize], start_ms: f64) -> PieceAnimation {
+    let keyframes = route.iter().enumerate()
+        .filter_map(|(step, &position)| {
+            let (x, y) = point(scene, &piece, position)?;
+            Some(Keyframe { t: start_ms + step as f64 * STEP_MS, x, y })
+        })
+        .collect();
+    PieceAnimation { piece, keyframes }
+}
+
+/// Animation for the last move on a board `board_size` across, or `None`
+/// before the first move
+pub fn last_move(state: &GameState, board_size: f64) -> Option<MoveAnimation> {
+    let outcome = state.last_move()?;
+    let scene = Scene::new(state, board_size);
+
+    let moved_route = route(outcome.moved.from, outcome.moved.to, true);
+    let moved = tween(&scene, outcome.moved, &moved_route, 0.0);
+    let arrival = moved.keyframes.last().map_or(0.0, |k| k.t);
+
+    // The captured piece is knocked away once the mover arrives: straight back
+    // to its start area, or back along the spiral when pushed back or swapped
+    let captured = outcome.captured.map(|victim| {
+        if victim.to == 0 {
+            let keyframes = [(arrival, victim.from), (arrival + KNOCK_BACK_MS, 0)].into_iter()
+                .filter_map(|(t, position)| {
+                    let (x, y) = point(&scene, &victim, position)?;
+                    Some(Keyframe { t, x, y })
+                })
+                .collect();
+            PieceAnimation { piece: victim, keyframes }
+        } else {
+            tween(&scene, victim, &route(victim.from, victim.to, false), arrival)
+        }
+    });
+
+    let duration_ms = captured.as_ref()
+        .and_then(|c| c.keyframes.last())
+        .map_or(arrival, |k| k.t);
+    Some(MoveAnimation { moved, captured, duration_ms })
+}
@@ -0,0 +1,314 @@
+//! Renderer-agnostic draw commands for canvas or WebGL front ends. Colours are
+//! given as style tokens (see `Paint`) and resolved with `palette`.
+
+use std::f64::consts::TAU;
+
+use serde::{Serialize, Serializer};
+
+use super::Scene;
+use crate::theme::Theme;
+use crate::{GameState, Player};
+
+/// A colour role, serialized as a token such as `"board-fill"` or `"piece-dark"`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Paint {
+    BoardFill,
+    BoardEdge,
+    SnakeBody,
+    SquareFill,
+    SquareNumber,
+    ValidMove,
+    HeadFill,
+    HeadEdge,
+    BenchFill,
+    BenchText,
+    ActiveBench,
+    /// A player's piece colour
+    Piece(Player),
+    /// Text drawn on a player's piece
+    PieceText(Player),
+}
+
+impl Paint {
+    pub fn token(self) -> String {
+        match self {
+            Paint::BoardFill => "board-fill".to_string(),
+            Paint::BoardEdge => "board-edge".to_string(),
+            Paint::SnakeBody => "snake-body".to_string(),
+            Paint::SquareFill => "square-fill".to_string(),
+            Paint::SquareNumber => "square-number".to_string(),
+            Paint::ValidMove => "valid-move".to_string(),
+            Paint::HeadFill => "head-fill".to_string(),
+            Paint::HeadEdge => "head-edge".to_string(),
+            Paint::BenchFill => "bench-fill".to_string(),
+            Paint::BenchText => "bench-text".to_string(),
+            Paint::ActiveBench => "active-bench".to_string(),
+            Paint::Piece(player) => format!("piece-{}", player.class_name()),
+            Paint::PieceText(player) => format!("piece-text-{}", player.class_name()),
+        }
+    }
+
+    /// CSS colour for the token in a theme
+    pub fn color(self, theme: &Theme) -> &str {
+        let board = &theme.board;
+        match self {
+            Paint::BoardFill => &board.board_fill,
+            Paint::BoardEdge => &board.board_edge,
+            Paint::SnakeBody => &board.snake_body,
+            Paint::SquareFill => &board.square_fill,
+            Paint::SquareNumber => &board.square_number,
+            Paint::ValidMove => &board.valid_move,
+            Paint::HeadFill => &board.head_fill,
+            Paint::HeadEdge => &board.head_edge,
+            Paint::BenchFill => &board.bench_fill,
+            Paint::BenchText => &board.bench_text,
+            Paint::ActiveBench => &board.active_bench,
+            Paint::Piece(player) => &theme.player(player).color,
+            Paint::PieceText(player) => &theme.player(player).text_color,
+        }
+    }
+}
+
+impl Serialize for Paint {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.token())
+    }
+}
+
+/// How a shape is filled and outlined
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct Style {
+    pub fill: Option<Paint>,
+    pub stroke: Option<Paint>,
+    pub line_width: f64,
+}
+
+impl Style {
+    fn fill(paint: Paint) -> Style {
+        Style { fill: Some(paint), stroke: None, line_width: 0.0 }
+    }
+
+    fn stroke(paint: Paint, line_width: f64) -> Style {
+        Style { fill: None, stroke: Some(paint), line_width }
+    }
+
+    fn outlined(fill: Paint, stroke: Paint, line_width: f64) -> Style {
+        Style { fill: Some(fill), stroke: Some(stroke), line_width }
+    }
+}
+
+/// Horizontal text alignment
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Align {
+    Left,
+    Center,
+    Right,
+}
+
+/// One drawing operation, in device pixels. Serialized with a `kind` field
+/// (`"circle"`, `"arc"`, `"path"` or `"text"`).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum DrawCommand {
+    Circle { x: f64, y: f64, r: f64, style: Style },
+    /// Angles in radians, clockwise from the positive x axis
+    Arc { x: f64, y: f64, r: f64, start: f64, end: f64, style: Style },
+    /// Straight segments through `points`; `round` joins and caps when stroked thickly
+    Path { points: Vec<(f64, f64)>, closed: bool, round: bool, style: Style },
+    /// `y` is the baseline
+    Text { x: f64, y: f64, text: String, size: f64, bold: bool, align: Align, style: Style },
+}
+
+/// Draw commands for the position on a board `board_size` CSS pixels across,
+/// scaled by the device pixel ratio `dpr`
+pub fn render(state: &GameState, board_size: f64, dpr: f64) -> Vec<DrawCommand> {
+    let scene = Scene::new(state, board_size);
+    let mut out = Vec::new();
+    let (cx, cy) = scene.center();
+    let size = scene.square_size;
+
+    // Board and the snake's body
+    out.push(DrawCommand::Circle {
+        x: cx,
+        y: cy,
+        r: board_size / 2.0 - 1.5,
+        style: Style::outlined(Paint::BoardFill, Paint::BoardEdge, 3.0),
+    });
+    let mut body: Vec<(f64, f64)> = scene.squares.iter().map(|s| (s.position.x, s.position.y)).collect();
+    body.push((cx, cy));
+    out.push(DrawCommand::Path {
+        points: body,
+        closed: false,
+        round: true,
+        style: Style::stroke(Paint::SnakeBody, size * 1.2),
+    });
+
+    // Segments, turned to follow the path, with their numbers and pieces
+    for square in &scene.squares {
+        let pos = &square.position;
+        let turn = pos.tangent.to_radians();
+        let corners = [(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)]
+            .iter()
+            .map(|&(u, v)| {
+                let (u, v) = (u * size / 2.0, v * size / 2.0);
+                (pos.x + u * turn.cos() - v * turn.sin(), pos.y + u * turn.sin() + v * turn.cos())
+            })
+            .collect();
+        let fill = match square.occupant {
+            Some(player) => Paint::Piece(player),
+            None if square.number == 36 => Paint::HeadFill,
+            None => Paint::SquareFill,
+        };
+        let (stroke, width) = if square.is_valid_move { (Paint::ValidMove, 3.0) } else { (Paint::BoardEdge, 2.0) };
+        out.push(DrawCommand::Path { points: corners, closed: true, round: false, style: Style::outlined(fill, stroke, width) });
+        out.push(DrawCommand::Text {
+            x: pos.x - size / 2.0 + 3.0,
+            y: pos.y - size / 2.0 + size * 0.25,
+            text: square.number.to_string(),
+            size: size * 0.2,
+            bold: false,
+            align: Align::Left,
+            style: Style::fill(Paint::SquareNumber),
+        });
+
+        if let Some(player) = square.occupant {
+            out.push(DrawCommand::Text {
+                x: pos.x,
+                y: pos.y + size * 0.2,
+                text: state.theme().player(player).glyph.clone(),
+                size: size * 0.55,
+                bold: true,
+                align: Align::Center,
+                style: Style::fill(Paint::PieceText(player)),
+            });
+            if square.stack > 1 {
+                out.push(DrawCommand::Text {
+                    x: pos.x + size / 2.0 - 3.0,
+                    y: pos.y + size / 2.0 - 3.0,
+                    text: square.stack.to_string(),
+                    size: size * 0.25,
+                    bold: true,
+                    align: Align::Right,
+                    style: Style::fill(Paint::PieceText(player)),
+                });
+            }
+        }
+    }
+
+    // The head, with each player's count of pieces home
+    let head = scene.head_radius();
+    out.push(DrawCommand::Circle { x: cx, y: cy, r: head, style: Style::outlined(Paint::HeadFill, Paint::HeadEdge, 3.0) });
+    let home: Vec<String> = state.players()
+        .filter_map(|p| {
+            let count = state.pieces(p).iter().filter(|&&pos| pos >= 36).count();
+            (count > 0).then(|| format!("{}{}", state.theme().player(p).glyph, count))
+        })
+        .collect();
+    if !home.is_empty() {
+        out.push(DrawCommand::Text {
+            x: cx,
+            y: cy + head * 0.15,
+            text: home.join(" "),
+            size: head * 0.4,
+            bold: false,
+            align: Align::Center,
+            style: Style::fill(Paint::HeadEdge),
+        });
+    }
+
+    // Benches below the board
+    for bench in &scene.benches {
+        let h = bench.height;
+        let mid = bench.y + h / 2.0;
+        let (edge, width) = if bench.active { (Paint::ActiveBench, 3.0) } else { (Paint::BoardEdge, 1.0) };
+        out.push(DrawCommand::Path {
+            points: vec![(2.0, bench.y + 2.0), (board_size - 2.0, bench.y + 2.0), (board_size - 2.0, bench.y + h - 2.0), (2.0, bench.y + h - 2.0)],
+            closed: true,
+            round: false,
+            style: Style::outlined(Paint::BenchFill, edge, width),
+        });
+        out.push(DrawCommand::Text {
+            x: h * 0.3,
+            y: mid + h * 0.12,
+            text: state.locale().player_name(bench.player).to_string(),
+            size: h * 0.35,
+            bold: true,
+            align: Align::Left,
+            style: Style::fill(Paint::BenchText),
+        });
+
+        let r = bench.piece_radius();
+        for slot in 0..bench.waiting.len() {
+            let (x, y) = bench.piece_center(slot);
+            out.push(DrawCommand::Circle { x, y, r, style: Style::outlined(Paint::Piece(bench.player), Paint::BoardEdge, 2.0) });
+            if bench.can_enter {
+                out.push(DrawCommand::Arc { x, y, r: r + 2.0, start: 0.0, end: TAU, style: Style::stroke(Paint::ValidMove, 2.0) });
+            }
+        }
+        out.push(DrawCommand::Text {
+            x: board_size - h * 0.3,
+            y: mid + h * 0.12,
+            text: state.locale().format("bench.home", &[("count", &bench.home.to_string())]),
+            size: h * 0.3,
+            bold: false,
+            align: Align::Right,
+            style: Style::fill(Paint::BenchText),
+        });
+    }
+
+    if dpr != 1.0 {
+        for command in &mut out {
+            command.scale(dpr);
+        }
+    }
+    out
+}
+
+impl DrawCommand {
+    /// Scale coordinates, sizes and line widths (CSS to device pixels)
+    fn scale(&mut self, k: f64) {
+        match self {
+            DrawCommand::Circle { x, y, r, style } | DrawCommand::Arc { x, y, r, style, .. } => {
+                *x *= k;
+                *y *= k;
+                *r *= k;
+                style.line_width *= k;
+            }
+            DrawCommand::Path { points, style, .. } => {
+                for (x, y) in points.iter_mut() {
+                    *x *= k;
+                    *y *= k;
+                }
+                style.line_width *= k;
+            }
+            DrawCommand::Text { x, y, size, style, .. } => {
+                *x *= k;
+                *y *= k;
+                *size *= k;
+                style.line_width *= k;
+            }
+        }
+    }
+}
+
+/// Every style token and its colour in the current theme, for resolving draw command styles
+pub fn palette(state: &GameState) -> Vec<(String, &str)> {
+    let fixed = [
+        Paint::BoardFill,
+        Paint::BoardEdge,
+        Paint::SnakeBody,
+        Paint::SquareFill,
+        Paint::SquareNumber,
+        Paint::ValidMove,
+        Paint::HeadFill,
+        Paint::HeadEdge,
+        Paint::BenchFill,
+        Paint::BenchText,
+        Paint::ActiveBench,
+    ];
+    let players = state.players().flat_map(|p| [Paint::Piece(p), Paint::PieceText(p)]);
+    fixed.into_iter().chain(players)
+        .map(|paint| (paint.token(), paint.color(state.theme())))
+        .collect()
+}
@@ -0,0 +1,218 @@
+//! Board drawing that works from the game state alone, without a DOM: the
+//! layout of everything on the board (`Scene`) and the renderers built on it.
+
+pub mod animation;
+pub mod commands;
+pub mod svg;
+
+use serde::Serialize;
+
+use crate::geometry::SquarePosition;
+use crate::{GameState, Player};
+
+/// Extra reach around squares and pieces when hit testing, in pixels
+pub const TOUCH_TOLERANCE: f64 = 8.0;
+
+/// Height of one player's bench below the board, as a fraction of the board size
+const BENCH_HEIGHT: f64 = 0.08;
+
+/// A square of the spiral and what is on it
+#[derive(Debug, Clone)]
+pub struct SceneSquare {
+    /// Square number (1-36)
+    pub number: usize,
+    pub position: SquarePosition,
+    pub occupant: Option<Player>,
+    /// Pieces on the square
+    pub stack: usize,
+    /// Holds a piece the player to move can move
+    pub is_valid_move: bool,
+}
+
+/// A player's waiting and finished pieces, drawn below the board
+#[derive(Debug, Clone)]
+pub struct Bench {
+    pub player: Player,
+    /// Top edge of the bench
+    pub y: f64,
+    pub height: f64,
+    /// Indices of the pieces waiting at the start
+    pub waiting: Vec<usize>,
+    /// Pieces home in the center
+    pub home: usize,
+    /// It is this player's turn
+    pub active: bool,
+    /// A waiting piece can enter the spiral with the current roll
+    pub can_enter: bool,
+}
+
+/// Everything on the board, laid out for a board `board_size` across
+#[derive(Debug, Clone)]
+pub struct Scene {
+    pub board_size: f64,
+    /// Full drawing height: the board plus the benches below it
+    pub height: f64,
+    /// Side of a square, small enough that neighbouring squares don't touch
+    pub square_size: f64,
+    pub squares: Vec<SceneSquare>,
+    pub benches: Vec<Bench>,
+}
+
+impl Scene {
+    pub fn new(state: &GameState, board_size: f64) -> Scene {
+        let positions = state.spiral_positions(board_size);
+        let square_size = square_size(&positions, board_size);
+        let stacks = state.stacks();
+        let movable = state.team_moves();
+
+        let squares = positions.into_iter().enumerate()
+            .map(|(i, position)| {
+                let number = i + 1;
+                let occupant = state.players()
+                    .find(|&p| number < 36 && state.pieces(p).contains(&number));
+                SceneSquare {
+                    number,
+                    position,
+                    occupant,
+                    stack: if number < 36 { stacks.get(i).copied().unwrap_or(0) } else { 0 },
+                    is_valid_move: number < 36 && movable.iter().any(|&(p, piece)| state.pieces(p)[piece] == number),
+                }
+            })
+            .collect();
+
+        let bench_height = board_size * BENCH_HEIGHT;
+        let benches = state.players().enumerate()
+            .map(|(seat, player)| {
+                let pieces = state.pieces(player);
+                Bench {
+                    player,
+                    y: board_size + seat as f64 * bench_height,
+                    height: bench_height,
+                    waiting: (0..pieces.len()).filter(|&i| pieces[i] == 0).collect(),
+                    home: pieces.iter().filter(|&&pos| pos >= 36).count(),
+                    active: player == state.current_player() && !state.game_over(),
+                    can_enter: movable.iter().any(|&(p, piece)| p == player && state.pieces(p)[piece] == 0),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        Scene {
+            board_size,
+            height: board_size + benches.len() as f64 * bench_height,
+            square_size,
+            squares,
+            benches,
+        }
+    }
+
+    /// Center of the board
+    pub fn center(&self) -> (f64, f64) {
+        (self.board_size / 2.0, self.board_size / 2.0)
+    }
+
+    /// Size of the snake's head in the center
+    pub fn head_radius(&self) -> f64 {
+        self.square_size * 0.6
+    }
+
+    /// What is under a point (in the same pixels as `board_size`). Squares and
+    /// pieces are `tolerance` pixels larger than drawn, for touch input; where
+    /// targets overlap, the one whose center is nearest wins.
+    pub fn hit_test(&self, x: f64, y: f64, tolerance: f64) -> Option<Hit> {
+        let mut best: Option<(f64, Hit)> = None;
+        let mut consider = |distance: f64, hit: Hit| {
+            if best.as_ref().is_none_or(|(d, _)| distance < *d) {
+                best = Some((distance, hit));
+            }
+        };
+
+        let half = self.square_size / 2.0 + tolerance;
+        for (index, square) in self.squares.iter().enumerate() {
+            // Into the square's own (rotated) frame
+            let (dx, dy) = (x - square.position.x, y - square.position.y);
+            let turn = square.position.tangent.to_radians();
+            let u = dx * turn.cos() + dy * turn.sin();
+            let v = -dx * turn.sin() + dy * turn.cos();
+            if u.abs() <= half && v.abs() <= half {
+                consider(dx.hypot(dy), Hit::Square { index });
+            }
+        }
+
+        let (cx, cy) = self.center();
+        let to_center = (x - cx).hypot(y - cy);
+        if to_center <= self.head_radius() + tolerance {
+            consider(to_center, Hit::Center);
+        }
+
+        for bench in &self.benches {
+            for (slot, &piece) in bench.waiting.iter().enumerate() {
+                let (px, py) = bench.piece_center(slot);
+                let distance = (x - px).hypot(y - py);
+                if distance <= bench.piece_radius() + tolerance {
+                    consider(distance, Hit::StartPiece { player: bench.player, piece });
+                }
+            }
+        }
+
+        best.map(|(_, hit)| hit)
+    }
+}
+
+impl Bench {
+    /// Size of a waiting piece
+    pub fn piece_radius(&self) -> f64 {
+        self.height * 0.3
+    }
+
+    /// Where the `slot`th waiting piece is drawn
+    pub fn piece_center(&self, slot: usize) -> (f64, f64) {
+        let x = self.height * 2.2 + slot as f64 * self.piece_radius() * 2.4;
+        (x, self.y + self.height / 2.0)
+    }
+}
+
+/// Something under the pointer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Hit {
+    /// A square of the spiral, by index (0-35, as in `get_spiral_positions`)
+    Square { index: usize },
+    /// A piece waiting in a player's start area
+    StartPiece { player: Player, piece: usize },
+    /// The snake's head
+    Center,
+}
+
+/// Text made safe to put in HTML or SVG, inside elements or quoted attributes.
+/// Themes are loaded at run time, so their strings need this before going into markup.
+pub fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Largest square side that keeps any two squares apart, capped at a tenth of
+/// the board so squares on a loose spiral don't grow huge
+pub fn square_size(positions: &[SquarePosition], board_size: f64) -> f64 {
+    let mut closest = f64::INFINITY;
+    for (i, a) in positions.iter().enumerate() {
+        for b in &positions[i + 1..] {
+            closest = closest.min((a.x - b.x).hypot(a.y - b.y));
+        }
+    }
+    if closest.is_finite() {
+        // Squares may be rotated, so keep their diagonals apart
+        (closest / std::f64::consts::SQRT_2 * 0.95).min(board_size * 0.1)
+    } else {
+        board_size * 0.1
+    }
+}
@@ -0,0 +1,200 @@
+//! Standalone SVG drawing of the current position, for scalable display,
+//! printed game records and server-side thumbnails.
+
+use std::fmt::Write;
+
+use super::{escape, Scene};
+use crate::i18n::Locale;
+use crate::theme::Theme;
+use crate::GameState;
+
+/// Complete SVG document of the position on a board `board_size` across, with
+/// each player's bench (waiting and finished pieces) below it
+pub fn render(state: &GameState, board_size: f64) -> String {
+    let scene = Scene::new(state, board_size);
+    let mut out = String::new();
+
+    writeln!(
+        out,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w:.0}" height="{h:.0}" viewBox="0 0 {w:.1} {h:.1}" font-family="sans-serif">"#,
+        w = scene.board_size,
+        h = scene.height,
+    ).unwrap();
+
+    let theme = state.theme();
+    board(&mut out, theme, &scene);
+    snake(&mut out, theme, &scene);
+    squares(&mut out, theme, &scene);
+    head(&mut out, state, &scene);
+    benches(&mut out, theme, state.locale(), &scene);
+
+    out.push_str("</svg>\n");
+    out
+}
+
+fn board(out: &mut String, theme: &Theme, scene: &Scene) {
+    let (cx, cy) = scene.center();
+    let board = &theme.board;
+    writeln!(
+        out,
+        r#"  <circle cx="{cx:.1}" cy="{cy:.1}" r="{r:.1}" fill="{f}" stroke="{e}" stroke-width="3"/>"#,
+        r = scene.board_size / 2.0 - 1.5,
+        f = escape(&board.board_fill),
+        e = escape(&board.board_edge),
+    ).unwrap();
+}
+
+/// The snake's body: a thick line through every square, ending at the head
+fn snake(out: &mut String, theme: &Theme, scene: &Scene) {
+    let (cx, cy) = scene.center();
+    let mut d = String::new();
+    for (i, square) in scene.squares.iter().enumerate() {
+        let command = if i == 0 { 'M' } else { 'L' };
+        write!(d, "{}{:.1} {:.1} ", command, square.position.x, square.position.y).unwrap();
+    }
+    write!(d, "L{:.1} {:.1}", cx, cy).unwrap();
+
+    writeln!(
+        out,
+        r#"  <path d="{d}" fill="none" stroke="{c}" stroke-width="{w:.1}" stroke-linecap="round" stroke-linejoin="round"/>"#,
+        c = escape(&theme.board.snake_body),
+        w = scene.square_size * 1.2,
+    ).unwrap();
+}
+
+/// Numbered segments with the pieces on them
+fn squares(out: &mut String, theme: &Theme, scene: &Scene) {
+    let size = scene.square_size;
+    let board = &theme.board;
+    for square in &scene.squares {
+        let pos = &square.position;
+        let fill = match square.occupant {
+            Some(player) => &theme.player(player).color,
+            None if square.number == 36 => &board.head_fill,
+            None => &board.square_fill,
+        };
+        let (stroke, stroke_width) = if square.is_valid_move { (&board.valid_move, 3.0) } else { (&board.board_edge, 2.0) };
+        let (fill, stroke) = (escape(fill), escape(stroke));
+
+        // Segments follow the path; their labels stay upright
+        writeln!(out, r#"  <g transform="translate({:.1} {:.1})">"#, pos.x, pos.y).unwrap();
+        writeln!(
+            out,
+            r#"    <rect x="{o:.1}" y="{o:.1}" width="{size:.1}" height="{size:.1}" rx="5" transform="rotate({r:.1})" fill="{fill}" stroke="{stroke}" stroke-width="{stroke_width}"/>"#,
+            o = -size / 2.0,
+            r = pos.tangent,
+        ).unwrap();
+        writeln!(
+            out,
+            r#"    <text x="{x:.1}" y="{y:.1}" font-size="{f:.1}" fill="{c}">{n}</text>"#,
+            c = escape(&board.square_number),
+            x = -size / 2.0 + 3.0,
+            y = -size / 2.0 + size * 0.25,
+            f = size * 0.2,
+            n = square.number,
+        ).unwrap();
+
+        if let Some(player) = square.occupant {
+            let style = theme.player(player);
+            writeln!(
+                out,
+                r#"    <text y="{y:.1}" font-size="{f:.1}" font-weight="bold" text-anchor="middle" fill="{c}">{g}</text>"#,
+                y = size * 0.2,
+                f = size * 0.55,
+                c = escape(&style.text_color),
+                g = escape(&style.glyph),
+            ).unwrap();
+            if square.stack > 1 {
+                writeln!(
+                    out,
+                    r#"    <text x="{x:.1}" y="{y:.1}" font-size="{f:.1}" font-weight="bold" text-anchor="end" fill="{c}">{n}</text>"#,
+                    x = size / 2.0 - 3.0,
+                    y = size / 2.0 - 3.0,
+                    f = size * 0.25,
+                    c = escape(&style.text_color),
+                    n = square.stack,
+                ).unwrap();
+            }
+        }
+        out.push_str("  </g>\n");
+    }
+}
+
+/// The snake's head in the center, showing how many pieces each player has home
+fn head(out: &mut String, state: &GameState, scene: &Scene) {
+    let (cx, cy) = scene.center();
+    let rx = scene.head_radius();
+    let board = &state.theme().board;
+    writeln!(
+        out,
+        r#"  <ellipse cx="{cx:.1}" cy="{cy:.1}" rx="{rx:.1}" ry="{ry:.1}" fill="{f}" stroke="{e}" stroke-width="3"/>"#,
+        ry = rx * 0.8,
+        f = escape(&board.head_fill),
+        e = escape(&board.head_edge),
+    ).unwrap();
+
+    let home: Vec<String> = state.players()
+        .filter_map(|p| {
+            let count = state.pieces(p).iter().filter(|&&pos| pos >= 36).count();
+            (count > 0).then(|| format!("{}{}", state.theme().player(p).glyph, count))
+        })
+        .collect();
+    if !home.is_empty() {
+        writeln!(
+            out,
+            r#"  <text x="{cx:.1}" y="{y:.1}" font-size="{f:.1}" text-anchor="middle" fill="{c}">{t}</text>"#,
+            c = escape(&board.head_edge),
+            y = cy + rx * 0.15,
+            f = rx * 0.4,
+            t = escape(&home.join(" ")),
+        ).unwrap();
+    }
+}
+
+/// A strip per player below the board with their waiting pieces and home count
+fn benches(out: &mut String, theme: &Theme, locale: Locale, scene: &Scene) {
+    let board = &theme.board;
+    for bench in &scene.benches {
+        let h = bench.height;
+        let mid = bench.y + h / 2.0;
+        let stroke = escape(if bench.active { &board.active_bench } else { &board.board_edge });
+        writeln!(
+            out,
+            r#"  <rect x="2" y="{y:.1}" width="{w:.1}" height="{hh:.1}" rx="6" fill="{f}" stroke="{stroke}" stroke-width="{sw}"/>"#,
+            f = escape(&board.bench_fill),
+            y = bench.y + 2.0,
+            w = scene.board_size - 4.0,
+            hh = h - 4.0,
+            sw = if bench.active { 3 } else { 1 },
+        ).unwrap();
+        writeln!(
+            out,
+            r#"  <text x="{x:.1}" y="{y:.1}" font-size="{f:.1}" font-weight="bold" fill="{c}">{name}</text>"#,
+            c = escape(&board.bench_text),
+            x = h * 0.3,
+            y = mid + h * 0.12,
+            f = h * 0.35,
+            name = escape(locale.player_name(bench.player)),
+        ).unwrap();
+
+        let r = bench.piece_radius();
+        for slot in 0..bench.waiting.len() {
+            let (x, y) = bench.piece_center(slot);
+            let stroke = escape(if bench.can_enter { &board.valid_move } else { &board.board_edge });
+            writeln!(
+                out,
+                r#"  <circle cx="{x:.1}" cy="{y:.1}" r="{r:.1}" fill="{c}" stroke="{stroke}" stroke-width="2"/>"#,
+                c = escape(&theme.player(bench.player).color),
+            ).unwrap();
+        }
+        writeln!(
+            out,
+            r#"  <text x="{x:.1}" y="{y:.1}" font-size="{f:.1}" text-anchor="end" fill="{c}">{home}</text>"#,
+            c = escape(&board.bench_text),
+            x = scene.board_size - h * 0.3,
+            y = mid + h * 0.12,
+            f = h * 0.3,
+            home = escape(&locale.format("bench.home", &[("count", &bench.home.to_string())])),
+        ).unwrap();
+    }
+}
@@ -0,0 +1,100 @@
+use serde::{Deserialize, Serialize};
+
+use crate::Player;
+
+/// How a game ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GameResult {
+    /// The player (or their team) brought every piece home
+    AllPiecesHome(Player),
+    /// The player resigned
+    Resignation(Player),
+    /// The player's clock ran out
+    Timeout(Player),
+    /// The game was left unfinished
+    Abandoned,
+    /// The players agreed a draw (only where the setup allows draws)
+    Draw,
+}
+
+impl GameResult {
+    /// Player who lost the game by their own action or clock, if any
+    pub fn loser(self) -> Option<Player> {
+        match self {
+            GameResult::Resignation(player) | GameResult::Timeout(player) => Some(player),
+            _ => None,
+        }
+    }
+
+    /// Short label for logs and standings exports
+    pub fn label(self) -> &'static str {
+        match self {
+            GameResult::AllPiecesHome(_) => "home",
+            GameResult::Resignation(_) => "resignation",
+            GameResult::Timeout(_) => "timeout",
+            GameResult::Abandoned => "abandoned",
+            GameResult::Draw => "draw",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GameState;
+
+    fn three_players() -> GameState {
+        GameState::from_position(vec![vec![4], vec![9], vec![14]], Player::Dark, 0).unwrap()
+    }
+
+    #[test]
+    fn resigning_leaves_the_others_playing_until_one_is_left() {
+        let mut state = three_players();
+        assert!(state.resign(Player::Dark));
+        assert!(!state.game_over());
+        assert!(!state.is_playing(Player::Dark));
+        assert_eq!(state.pieces(Player::Dark), &[0]);
+        assert_eq!(state.current_player(), Player::Red);
+        assert_eq!(state.next_player(Player::Light), Player::Red);
+        assert_eq!(state.next_player(Player::Red), Player::Light);
+        assert!(!state.resign(Player::Dark));
+
+        assert!(state.resign(Player::Red));
+        assert_eq!(state.result(), Some(GameResult::Resignation(Player::Red)));
+        assert_eq!(state.winner(), Some(Player::Light));
+    }
+
+    #[test]
+    fn resigning_a_two_player_game_gives_the_opponent_the_win() {
+        let mut state = GameState::from_position(vec![vec![4], vec![9]], Player::Light, 0).unwrap();
+        assert!(state.resign(Player::Light));
+        assert!(state.game_over());
+        assert_eq!(state.winner(), Some(Player::Dark));
+        assert_eq!(state.result().and_then(GameResult::loser), Some(Player::Light));
+    }
+
+    #[test]
+    fn draw_needs_every_side_and_lapses_on_a_move() {
+        let mut state = GameState::from_position(vec![vec![4], vec![9], vec![14], vec![19]], Player::Light, 0).unwrap();
+        state.setup.teams = 2;
+        assert!(!state.offer_draw(Player::Light));
+
+        state.setup.allow_draws = true;
+        assert!(state.offer_draw(Player::Light));
+        assert!(state.has_offered_draw(Player::Red));
+        assert!(state.offer_draw(Player::Red));
+        assert!(!state.game_over());
+        state.decline_draw();
+        assert!(!state.has_offered_draw(Player::Light));
+
+        assert!(state.offer_draw(Player::Dark));
+        state.dice_pool = vec![1];
+        assert!(state.make_move(0));
+        assert!(!state.has_offered_draw(Player::Dark));
+
+        assert!(state.offer_draw(Player::Dark));
+        assert!(state.offer_draw(Player::Red));
+        assert_eq!(state.result(), Some(GameResult::Draw));
+        assert_eq!(state.winner(), None);
+    }
+}
@@ -0,0 +1,301 @@
+use serde::{Deserialize, Serialize};
+
+/// Position of the center (the snake's head)
+pub const HOME: usize = 36;
+
+/// Most dice thrown in a turn
+pub const MAX_DICE: u8 = 4;
+
+/// What happens when a roll would carry a piece past the center
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum BearOff {
+    /// The piece needs the exact roll to reach the center
+    #[default]
+    Exact,
+    /// Any roll that reaches or passes the center takes the piece home
+    Overshoot,
+    /// The piece reaches the head and bounces back by the excess
+    Bounce,
+}
+
+/// What happens to an opponent's piece when a piece lands on it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Capture {
+    /// Pieces can't land on an opponent
+    None,
+    /// The captured piece goes back to the start
+    #[default]
+    ToStart,
+    /// The captured piece goes back this many squares
+    PushBack(u8),
+    /// The captured piece goes back by a fresh throw of the die
+    PushBackRolled,
+    /// The captured piece takes the square the capturing piece came from, or
+    /// goes back to the start if part of a stack is still there
+    Swap,
+}
+
+/// Whether a player's pieces may share a square
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Stacking {
+    /// A square holds one piece
+    #[default]
+    Off,
+    /// Pieces may stack but still move one at a time
+    Separate,
+    /// Moving a piece of a stack moves the whole stack
+    Unit,
+}
+
+/// The three turns of the spiral
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Ring {
+    /// Squares 1-18
+    Outer,
+    /// Squares 19-30
+    Middle,
+    /// Squares 31-35
+    Inner,
+}
+
+impl Ring {
+    /// Ring a board square lies on (None for the start and the center)
+    pub fn of(square: usize) -> Option<Ring> {
+        match square {
+            1..=18 => Some(Ring::Outer),
+            19..=30 => Some(Ring::Middle),
+            31..=35 => Some(Ring::Inner),
+            _ => None,
+        }
+    }
+}
+
+/// Movement rules. Selected through `GameSetup::rules`; from JavaScript this is
+/// a plain object such as `{ dice_count: 2, bear_off: "Bounce" }`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Rules {
+    /// Dice thrown each turn (1 to `MAX_DICE`). Each die moves a piece on its own,
+    /// in whichever order the player likes.
+    pub dice_count: u8,
+    pub bear_off: BearOff,
+    /// Rolls that bring a piece onto the spiral from the start (empty = any roll)
+    pub entry_rolls: Vec<u8>,
+    /// Only one piece may enter the spiral per turn
+    pub single_entry: bool,
+    /// The roll may be split across several pieces (e.g. 2 + 3 from a 5)
+    pub split_roll: bool,
+    pub capture: Capture,
+    /// Rings where pieces can't be captured
+    pub safe_rings: Vec<Ring>,
+    /// A player's own pieces may stack; a stack of two or more can't be captured
+    pub stacking: Stacking,
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Rules {
+            dice_count: 1,
+            bear_off: BearOff::default(),
+            entry_rolls: Vec::new(),
+            single_entry: false,
+            split_roll: false,
+            capture: Capture::default(),
+            safe_rings: Vec::new(),
+            stacking: Stacking::default(),
+        }
+    }
+}
+
+impl Rules {
+    /// Check options that came from outside (e.g. JavaScript)
+    pub fn validate(&self) -> Result<(), String> {
+        if self.dice_count == 0 || self.dice_count > MAX_DICE {
+            return Err(format!("A turn needs 1 to {} dice, not {}", MAX_DICE, self.dice_count));
+        }
+        if let Some(roll) = self.entry_rolls.iter().find(|&&roll| roll == 0 || roll > 6) {
+            return Err(format!("Entry roll {} is out of range", roll));
+        }
+        if self.capture == Capture::PushBack(0) {
+            return Err("Captured pieces must be pushed back at least one square".to_string());
+        }
+        Ok(())
+    }
+
+    /// Whether a piece on `square` can be captured
+    pub fn can_capture_on(&self, square: usize) -> bool {
+        self.capture != Capture::None
+            && Ring::of(square).is_some_and(|ring| !self.safe_rings.contains(&ring))
+    }
+
+    /// Whether a roll lets a piece enter the spiral
+    pub fn can_enter(&self, roll: u8) -> bool {
+        self.entry_rolls.is_empty() || self.entry_rolls.contains(&roll)
+    }
+
+    /// Where a piece at `from` ends up after moving `steps` squares, or `None`
+    /// if the end-game rule doesn't allow the move
+    pub fn destination(&self, from: usize, steps: usize) -> Option<usize> {
+        let target = from + steps;
+        if target <= HOME {
+            return Some(target);
+        }
+        match self.bear_off {
+            BearOff::Exact => None,
+            BearOff::Overshoot => Some(HOME),
+            BearOff::Bounce => Some(HOME - (target - HOME)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{GameState, Player};
+
+    /// Light to move with `dice` thrown, under `rules`
+    fn game(rules: Rules, light: Vec<usize>, dark: Vec<usize>, dice: &[u8]) -> GameState {
+        let mut state = GameState::from_position(vec![light, dark], Player::Light, 0).unwrap();
+        state.setup.rules = rules;
+        state.dice_pool = dice.to_vec();
+        state
+    }
+
+    #[test]
+    fn entry_roll_cannot_be_split() {
+        let rules = Rules { split_roll: true, entry_rolls: vec![1], ..Rules::default() };
+        let state = game(rules.clone(), vec![0, 10], vec![0, 0], &[6]);
+        assert!(!state.can_move_by(0, 1));
+        assert!(!state.can_move_by(0, 6));
+        assert!(state.can_move_by(1, 1));
+
+        let state = game(rules, vec![0, 10], vec![0, 0], &[1]);
+        assert!(state.can_move_by(0, 1));
+    }
+
+    #[test]
+    fn dice_are_listed_and_played_separately() {
+        let mut state = game(Rules::default(), vec![5], vec![20], &[2, 2, 4]);
+        assert_eq!(state.die_moves(), vec![(Player::Light, 0, 0), (Player::Light, 0, 2)]);
+
+        assert!(state.make_move_with_die(0, 2));
+        assert_eq!(state.pieces(Player::Light), &[9]);
+        assert_eq!(state.dice(), &[2, 2]);
+        assert!(!state.make_move_with_die(0, 2));
+    }
+
+    #[test]
+    fn bear_off_rules_decide_rolls_past_the_center() {
+        let exact = game(Rules::default(), vec![33, 1], vec![20], &[5]);
+        assert!(!exact.can_move(0));
+        let mut exact = game(Rules::default(), vec![33, 1], vec![20], &[3]);
+        assert!(exact.make_move(0));
+        assert_eq!(exact.pieces(Player::Light)[0], HOME);
+
+        let mut overshoot = game(Rules { bear_off: BearOff::Overshoot, ..Rules::default() }, vec![33, 1], vec![20], &[5]);
+        assert!(overshoot.make_move(0));
+        assert_eq!(overshoot.pieces(Player::Light)[0], HOME);
+
+        let mut bounce = game(Rules { bear_off: BearOff::Bounce, ..Rules::default() }, vec![33, 1], vec![20], &[5]);
+        assert!(bounce.make_move(0));
+        assert_eq!(bounce.pieces(Player::Light)[0], 34);
+    }
+
+    #[test]
+    fn entry_rules_limit_pieces_entering() {
+        let rules = Rules { entry_rolls: vec![1], ..Rules::default() };
+        assert!(!game(rules.clone(), vec![0, 10], vec![20], &[3]).can_move(0));
+        assert!(game(rules, vec![0, 10], vec![20], &[1]).can_move(0));
+
+        let rules = Rules { dice_count: 2, single_entry: true, ..Rules::default() };
+        let mut state = game(rules, vec![0, 0], vec![20], &[1, 2]);
+        assert!(state.make_move_with_die(0, 0));
+        assert_eq!(state.current_player(), Player::Light);
+        assert!(!state.can_move(1));
+        assert!(state.can_move(0));
+    }
+
+    #[test]
+    fn split_roll_moves_several_pieces() {
+        let rules = Rules { split_roll: true, ..Rules::default() };
+        let mut state = game(rules, vec![3, 10], vec![20], &[5]);
+        assert!(state.make_move_by(0, 2));
+        assert_eq!(state.dice(), &[3]);
+        assert_eq!(state.current_player(), Player::Light);
+        assert!(!state.can_move_by(1, 4));
+
+        assert!(state.make_move_by(1, 3));
+        assert_eq!(state.pieces(Player::Light), &[5, 13]);
+        assert_eq!(state.current_player(), Player::Dark);
+    }
+
+    #[test]
+    fn unusable_dice_left_in_the_pool_are_forfeited() {
+        let rules = Rules { dice_count: 2, ..Rules::default() };
+        let mut state = game(rules, vec![30], vec![20], &[6, 5]);
+        assert!(state.make_move_with_die(0, 1));
+        assert_eq!(state.pieces(Player::Light), &[35]);
+        assert!(state.dice().is_empty());
+        assert_eq!(state.current_player(), Player::Dark);
+    }
+
+    #[test]
+    fn capture_rules_decide_where_the_victim_goes() {
+        let victim_after = |rules: Rules| {
+            let mut state = game(rules, vec![10], vec![12], &[2]);
+            state.make_move(0).then(|| state.pieces(Player::Dark)[0])
+        };
+        assert_eq!(victim_after(Rules::default()), Some(0));
+        assert_eq!(victim_after(Rules { capture: Capture::None, ..Rules::default() }), None);
+        assert_eq!(victim_after(Rules { capture: Capture::PushBack(3), ..Rules::default() }), Some(9));
+        assert_eq!(victim_after(Rules { capture: Capture::Swap, ..Rules::default() }), Some(10));
+        assert_eq!(victim_after(Rules { safe_rings: vec![Ring::Outer], ..Rules::default() }), None);
+    }
+
+    #[test]
+    fn stacks_move_together_and_cannot_be_captured() {
+        assert!(!game(Rules::default(), vec![5, 7], vec![20], &[2]).can_move(0));
+
+        let separate = Rules { stacking: Stacking::Separate, ..Rules::default() };
+        let mut state = game(separate.clone(), vec![5, 7], vec![20], &[2]);
+        assert!(state.make_move(0));
+        assert_eq!(state.stacks()[6], 2);
+
+        let mut state = game(Rules { stacking: Stacking::Unit, ..Rules::default() }, vec![0, 0, 0], vec![20], &[2]);
+        state.players[0].pieces = vec![7, 7, 1];
+        assert!(state.make_move(0));
+        assert_eq!(state.pieces(Player::Light), &[9, 9, 1]);
+        assert_eq!(state.last_move().unwrap().stack, 2);
+
+        let mut state = game(separate.clone(), vec![5], vec![20], &[2]);
+        state.players[1].pieces = vec![7, 7];
+        assert!(!state.can_move(0));
+
+        // A swap can't put the victim beside the rest of the mover's stack
+        let mut state = game(Rules { capture: Capture::Swap, ..separate }, vec![0, 0], vec![7], &[2]);
+        state.players[0].pieces = vec![5, 5];
+        assert!(state.make_move(0));
+        assert_eq!(state.pieces(Player::Light), &[7, 5]);
+        assert_eq!(state.pieces(Player::Dark), &[0]);
+    }
+
+    #[test]
+    fn split_steps_count_as_moves_everywhere() {
+        let rules = Rules { split_roll: true, ..Rules::default() };
+        let state = game(rules, vec![33, 36, 36, 36, 36, 36], vec![20], &[5]);
+        assert_eq!(state.team_moves(), vec![(Player::Light, 0)]);
+        assert!(!state.should_auto_pass_turn(5));
+        let targets: Vec<usize> = crate::a11y::focus_targets(&state).iter().map(|target| target.to).collect();
+        assert_eq!(targets, vec![34, 35, 36]);
+
+        let mut keyboard = state.clone();
+        keyboard.move_focus(1);
+        assert!(keyboard.play_focus());
+        assert_eq!(keyboard.pieces(Player::Light)[0], 34);
+
+        let mut click = state;
+        assert!(click.make_move(0));
+        assert_eq!(click.pieces(Player::Light)[0], HOME);
+        assert_eq!(click.winner(), Some(Player::Light));
+    }
+}
@@ -0,0 +1,135 @@
+use serde::{Deserialize, Serialize};
+
+use crate::clock::TimeControl;
+use crate::geometry::SpiralLayout;
+use crate::rules::Rules;
+use crate::{Player, MAX_PLAYERS};
+
+/// Who takes the first turn
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum StartingPlayer {
+    #[default]
+    Light,
+    Dark,
+    /// Any player, chosen at random
+    Random,
+    /// Everyone throws; the highest throw starts and ties throw again
+    RollOff,
+    /// Light starts the first game, then the first player moves round the table on every reset
+    Alternate,
+}
+
+/// Options for starting (or resetting) a game.
+///
+/// From JavaScript this is a plain object, e.g. `{ starting_player: "RollOff" }`;
+/// any field left out keeps its default.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameSetup {
+    /// Number of players (2 to 6), seated Light, Dark, Red, Blue, Green, Teal
+    pub players: usize,
+    pub starting_player: StartingPlayer,
+    /// Seed for reproducible dice (None = `Math.random()`)
+    pub dice_seed: Option<u64>,
+    /// Number of partnerships (0 = everyone for themselves). Seats are dealt into
+    /// teams in turn, so 4 players in 2 teams are Light & Red against Dark & Blue
+    /// and turns alternate between the teams.
+    pub teams: usize,
+    /// Partners may spend their roll on any piece of the team
+    pub share_rolls: bool,
+    /// Chess-style clocks (None = untimed)
+    pub time_control: Option<TimeControl>,
+    /// Players may agree a draw
+    pub allow_draws: bool,
+    /// Movement rules (entering the spiral, the end game)
+    pub rules: Rules,
+    /// Shape of the spiral the board is drawn on
+    pub layout: SpiralLayout,
+}
+
+impl Default for GameSetup {
+    fn default() -> Self {
+        GameSetup {
+            players: 2,
+            starting_player: StartingPlayer::default(),
+            dice_seed: None,
+            teams: 0,
+            share_rolls: false,
+            time_control: None,
+            allow_draws: false,
+            rules: Rules::default(),
+            layout: SpiralLayout::default(),
+        }
+    }
+}
+
+impl GameSetup {
+    /// Check options that came from outside (e.g. JavaScript)
+    pub fn validate(&self) -> Result<(), String> {
+        if self.players < 2 || self.players > MAX_PLAYERS {
+            return Err(format!("A game needs 2 to {} players, not {}", MAX_PLAYERS, self.players));
+        }
+        if let Some(control) = self.time_control {
+            if control.initial_ms <= 0.0 || control.increment_ms < 0.0 || control.delay_ms < 0.0 {
+                return Err("Clock times must be positive".to_string());
+            }
+        }
+        if self.teams != 0 && self.team_count() == 0 {
+            return Err(format!("{} players can't be split into {} teams of two or more", self.players, self.teams));
+        }
+        self.rules.validate()?;
+        self.layout.validate()
+    }
+
+    /// Number of players, clamped to what the board supports
+    pub fn player_count(&self) -> usize {
+        self.players.clamp(2, MAX_PLAYERS)
+    }
+
+    /// Number of teams actually in play (0 when the team setup doesn't fit the players)
+    pub fn team_count(&self) -> usize {
+        let players = self.player_count();
+        if self.teams >= 2 && players.is_multiple_of(self.teams) && players / self.teams >= 2 {
+            self.teams
+        } else {
+            0
+        }
+    }
+
+    /// Team a player belongs to, if playing in teams
+    pub fn team_of(&self, player: Player) -> Option<usize> {
+        match self.team_count() {
+            0 => None,
+            teams => Some(player.index() % teams),
+        }
+    }
+
+    /// Whether two players are on the same side (a player is always on their own side)
+    pub fn same_team(&self, a: Player, b: Player) -> bool {
+        a == b || (self.team_of(a).is_some() && self.team_of(a) == self.team_of(b))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{GameState, Player};
+
+    #[test]
+    fn shared_rolls_move_partners_pieces_from_the_board() {
+        let mut state = GameState::from_position(vec![vec![0], vec![0], vec![5], vec![0]], Player::Light, 0).unwrap();
+        state.setup.teams = 2;
+        state.setup.share_rolls = true;
+        state.dice_pool = vec![3];
+
+        // Red (Light's partner) has a piece on square 5, board square index 4
+        assert_eq!(state.move_at_square(4), Some((Player::Red, 0)));
+        assert!(state.is_square_valid_move(4));
+        assert!(state.make_move_piece(Player::Red, 0));
+        assert_eq!(state.pieces(Player::Red), &[8]);
+
+        state.setup.share_rolls = false;
+        state.dice_pool = vec![3];
+        state.current_player = Player::Light;
+        assert_eq!(state.move_at_square(7), None);
+    }
+}
@@ -0,0 +1,63 @@
+use serde::{Deserialize, Serialize};
+
+use crate::Player;
+
+/// Race and capture figures for one player
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlayerStats {
+    pub player: Player,
+    /// Squares the player's pieces still have to travel
+    pub pips: usize,
+    pub at_start: usize,
+    pub on_board: usize,
+    pub home: usize,
+    pub captures_made: u32,
+    pub captures_suffered: u32,
+    /// Turns the player had to pass without moving
+    pub turns_passed: u32,
+}
+
+/// Statistics for the game so far (see `GameState::stats`)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GameStats {
+    /// One entry per player, in seat order
+    pub players: Vec<PlayerStats>,
+    /// Moves made so far
+    pub moves: u32,
+    /// Most moves in a row without a capture
+    pub longest_capture_free: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{GameState, Player};
+
+    /// The player to move throws `die` and moves `piece`
+    fn play(state: &mut GameState, die: u8, piece: usize) {
+        state.dice_pool = vec![die];
+        assert!(state.make_move(piece));
+    }
+
+    #[test]
+    fn captures_passes_and_quiet_runs_are_counted() {
+        let mut state = GameState::from_position(vec![vec![10, 1], vec![20, 2]], Player::Light, 0).unwrap();
+        play(&mut state, 3, 1); // Light 1 -> 4
+        play(&mut state, 2, 1); // Dark 2 -> 4, capturing
+        let stats = state.stats();
+        assert_eq!((stats.players[1].captures_made, stats.players[0].captures_suffered), (1, 1));
+        assert_eq!((stats.players[0].captures_made, stats.players[1].captures_suffered), (0, 0));
+        assert_eq!(stats.longest_capture_free, 1);
+
+        state.dice_pool = vec![6];
+        state.pass_turn();
+        play(&mut state, 1, 0); // Dark 20 -> 21
+        play(&mut state, 1, 0); // Light 10 -> 11
+        play(&mut state, 1, 0); // Dark 21 -> 22
+
+        let stats = state.stats();
+        assert_eq!(stats.moves, 5);
+        assert_eq!(stats.longest_capture_free, 3);
+        assert_eq!((stats.players[0].turns_passed, stats.players[1].turns_passed), (1, 0));
+        assert_eq!(stats.players[0].at_start, 1);
+    }
+}
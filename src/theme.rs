@@ -0,0 +1,184 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{Player, MAX_PLAYERS};
+
+/// How one player's pieces look
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlayerStyle {
+    pub color: String,
+    /// Colour of the glyph drawn on the piece
+    pub text_color: String,
+    pub glyph: String,
+    /// Picture to draw instead of the glyph (URL), if any
+    #[serde(default)]
+    pub image: Option<String>,
+}
+
+/// Board colours
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BoardPalette {
+    pub board_fill: String,
+    pub board_edge: String,
+    pub snake_body: String,
+    pub square_fill: String,
+    pub square_number: String,
+    pub valid_move: String,
+    pub head_fill: String,
+    pub head_edge: String,
+    pub bench_fill: String,
+    pub bench_text: String,
+    pub active_bench: String,
+}
+
+/// Colours of the status line
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StatusColors {
+    pub playing: String,
+    pub game_over: String,
+}
+
+/// Colours and glyphs for everything the game draws. Load one from JSON with
+/// `Theme::from_json`; any section left out is taken from the classic theme.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Theme {
+    pub name: String,
+    /// One style per seat, in seat order (Light, Dark, Red, Blue, Green, Teal)
+    pub players: Vec<PlayerStyle>,
+    pub board: BoardPalette,
+    pub status: StatusColors,
+}
+
+/// Names of the built-in themes
+pub const BUILTIN_THEMES: [&str; 3] = ["classic", "high-contrast", "colour-blind-safe"];
+
+fn player_styles(styles: [(&str, &str); MAX_PLAYERS]) -> Vec<PlayerStyle> {
+    Player::ALL.iter().zip(styles)
+        .map(|(player, (color, text_color))| PlayerStyle {
+            color: color.to_string(),
+            text_color: text_color.to_string(),
+            glyph: player.symbol().to_string(),
+            image: None,
+        })
+        .collect()
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme::classic()
+    }
+}
+
+impl Theme {
+    /// The original look (matches style.css)
+    pub fn classic() -> Theme {
+        Theme {
+            name: "classic".to_string(),
+            players: Player::ALL.iter()
+                .map(|player| PlayerStyle {
+                    color: player.color().to_string(),
+                    text_color: player.text_color().to_string(),
+                    glyph: player.symbol().to_string(),
+                    image: None,
+                })
+                .collect(),
+            board: BoardPalette {
+                board_fill: "#d4a574".to_string(),
+                board_edge: "#8b6f47".to_string(),
+                snake_body: "#c9a068".to_string(),
+                square_fill: "#f5e6d3".to_string(),
+                square_number: "#666".to_string(),
+                valid_move: "#00ff00".to_string(),
+                head_fill: "#ffd700".to_string(),
+                head_edge: "#8b4513".to_string(),
+                bench_fill: "#f8f9fa".to_string(),
+                bench_text: "#333".to_string(),
+                active_bench: "#667eea".to_string(),
+            },
+            status: StatusColors {
+                playing: "#667eea".to_string(),
+                game_over: "#ff6347".to_string(),
+            },
+        }
+    }
+
+    /// Black, white and saturated colours for low vision
+    pub fn high_contrast() -> Theme {
+        Theme {
+            name: "high-contrast".to_string(),
+            players: player_styles([
+                ("#ffffff", "#000000"),
+                ("#000000", "#ffffff"),
+                ("#ff0000", "#000000"),
+                ("#0000ff", "#ffffff"),
+                ("#00ff00", "#000000"),
+                ("#00ffff", "#000000"),
+            ]),
+            board: BoardPalette {
+                board_fill: "#ffffff".to_string(),
+                board_edge: "#000000".to_string(),
+                snake_body: "#bbbbbb".to_string(),
+                square_fill: "#ffffff".to_string(),
+                square_number: "#000000".to_string(),
+                valid_move: "#ff00ff".to_string(),
+                head_fill: "#ffff00".to_string(),
+                head_edge: "#000000".to_string(),
+                bench_fill: "#ffffff".to_string(),
+                bench_text: "#000000".to_string(),
+                active_bench: "#ff00ff".to_string(),
+            },
+            status: StatusColors {
+                playing: "#000000".to_string(),
+                game_over: "#d00000".to_string(),
+            },
+        }
+    }
+
+    /// Player colours from the Okabe-Ito palette, which stay distinct with
+    /// the common forms of colour blindness
+    pub fn colour_blind_safe() -> Theme {
+        Theme {
+            name: "colour-blind-safe".to_string(),
+            players: player_styles([
+                ("#f0e442", "#000000"),
+                ("#0072b2", "#ffffff"),
+                ("#d55e00", "#ffffff"),
+                ("#56b4e9", "#000000"),
+                ("#009e73", "#ffffff"),
+                ("#cc79a7", "#000000"),
+            ]),
+            board: BoardPalette {
+                valid_move: "#000000".to_string(),
+                active_bench: "#000000".to_string(),
+                ..Theme::classic().board
+            },
+            status: StatusColors {
+                playing: "#0072b2".to_string(),
+                game_over: "#d55e00".to_string(),
+            },
+        }
+    }
+
+    /// A built-in theme by name (see `BUILTIN_THEMES`)
+    pub fn builtin(name: &str) -> Option<Theme> {
+        match name {
+            "classic" => Some(Theme::classic()),
+            "high-contrast" => Some(Theme::high_contrast()),
+            "colour-blind-safe" => Some(Theme::colour_blind_safe()),
+            _ => None,
+        }
+    }
+
+    pub fn from_json(json: &str) -> Result<Theme, String> {
+        let theme: Theme = serde_json::from_str(json).map_err(|e| e.to_string())?;
+        if theme.players.len() != MAX_PLAYERS {
+            return Err(format!("A theme needs a style for each of the {} seats, not {}", MAX_PLAYERS, theme.players.len()));
+        }
+        Ok(theme)
+    }
+
+    /// Style of a player's pieces
+    pub fn player(&self, player: Player) -> &PlayerStyle {
+        &self.players[player.index()]
+    }
+}
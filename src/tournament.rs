@@ -0,0 +1,592 @@
+use serde::Serialize;
+
+use crate::dice::SeededDice;
+use crate::result::GameResult;
+use crate::{engine, GameState, Player};
+
+/// Safety limit so a pathological strategy can't stall a headless game forever;
+/// games that reach it are recorded as abandoned
+const MAX_TURNS: u32 = 10_000;
+
+/// Elo rating new entrants start with
+const START_ELO: f64 = 1500.0;
+
+/// Elo K-factor applied per game
+const ELO_K: f64 = 32.0;
+
+/// Glicko-1 starting deviation and the floor it can shrink to
+const START_DEVIATION: f64 = 350.0;
+const MIN_DEVIATION: f64 = 30.0;
+
+/// Something that picks a move for the current player
+pub trait Strategy {
+    /// Choose one of `moves` (never empty) for the current player of `game`
+    fn choose_move(&mut self, game: &GameState, moves: &[usize]) -> usize;
+}
+
+/// Plays the engine's best move
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EngineBot;
+
+impl Strategy for EngineBot {
+    fn choose_move(&mut self, game: &GameState, moves: &[usize]) -> usize {
+        engine::best_move(game).unwrap_or(moves[0])
+    }
+}
+
+/// Always advances the piece that is furthest along
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RunnerBot;
+
+impl Strategy for RunnerBot {
+    fn choose_move(&mut self, game: &GameState, moves: &[usize]) -> usize {
+        let pieces = game.pieces(game.current_player());
+        *moves.iter().max_by_key(|&&i| pieces[i]).unwrap_or(&moves[0])
+    }
+}
+
+/// Picks a legal move at random (seeded, so still reproducible)
+#[derive(Debug, Clone, Copy)]
+pub struct RandomBot {
+    dice: SeededDice,
+}
+
+impl RandomBot {
+    pub fn new(seed: u64) -> RandomBot {
+        RandomBot { dice: SeededDice::new(seed) }
+    }
+}
+
+impl Strategy for RandomBot {
+    fn choose_move(&mut self, _game: &GameState, moves: &[usize]) -> usize {
+        moves[(self.dice.next_f64() * moves.len() as f64) as usize]
+    }
+}
+
+/// One finished game between two entrants
+#[derive(Debug, Clone, Serialize)]
+pub struct GameRecord {
+    pub light: usize,
+    pub dark: usize,
+    /// Winning entrant, `None` for a draw or an abandoned game
+    pub winner: Option<usize>,
+    /// How the game ended
+    pub result: GameResult,
+    pub turns: u32,
+    pub seed: u64,
+}
+
+/// A best-of-N match between two entrants
+#[derive(Debug, Clone, Serialize)]
+pub struct MatchRecord {
+    pub first: usize,
+    pub second: usize,
+    pub games: Vec<GameRecord>,
+}
+
+impl MatchRecord {
+    pub fn new(first: usize, second: usize) -> MatchRecord {
+        MatchRecord { first, second, games: Vec::new() }
+    }
+
+    pub fn add_game(&mut self, game: GameRecord) {
+        self.games.push(game);
+    }
+
+    pub fn wins(&self, entrant: usize) -> u32 {
+        self.games.iter().filter(|g| g.winner == Some(entrant)).count() as u32
+    }
+
+    /// Match winner, `None` for a drawn match
+    pub fn winner(&self) -> Option<usize> {
+        let (first, second) = (self.wins(self.first), self.wins(self.second));
+        match first.cmp(&second) {
+            std::cmp::Ordering::Greater => Some(self.first),
+            std::cmp::Ordering::Less => Some(self.second),
+            std::cmp::Ordering::Equal => None,
+        }
+    }
+}
+
+/// Derive an independent seed for game `n` of a match
+fn game_seed(seed: u64, n: u64) -> u64 {
+    SeededDice::new(seed ^ n.wrapping_mul(0xA076_1D64_78BD_642F)).next_u64()
+}
+
+/// Borrow two different entries of a slice mutably
+fn pair_mut<T: ?Sized>(items: &mut [Box<T>], a: usize, b: usize) -> (&mut T, &mut T) {
+    assert_ne!(a, b, "an entrant can't play itself");
+    if a < b {
+        let (left, right) = items.split_at_mut(b);
+        (&mut *left[a], &mut *right[0])
+    } else {
+        let (left, right) = items.split_at_mut(a);
+        (&mut *right[0], &mut *left[b])
+    }
+}
+
+/// Play one headless game with seeded dice. Returns the finished game and the number of turns.
+pub fn play_game(light: &mut dyn Strategy, dark: &mut dyn Strategy, seed: u64) -> (GameState, u32) {
+    let mut game = GameState::new();
+    game.set_dice_seed(seed);
+
+    let mut turns = 0;
+    while !game.game_over() && turns < MAX_TURNS {
+        game.roll_dice();
+        let moves = game.valid_moves();
+        if moves.is_empty() {
+            game.pass_turn();
+        } else {
+            let strategy: &mut dyn Strategy = if game.current_player() == Player::Light {
+                &mut *light
+            } else {
+                &mut *dark
+            };
+            let choice = strategy.choose_move(&game, &moves);
+            let choice = if moves.contains(&choice) { choice } else { moves[0] };
+            game.make_move(choice);
+        }
+        turns += 1;
+    }
+
+    game.abandon();
+    (game, turns)
+}
+
+/// Play a best-of-N match. Light always starts, so the entrants swap colours every game.
+pub fn play_match(
+    strategies: &mut [Box<dyn Strategy>],
+    first: usize,
+    second: usize,
+    best_of: u32,
+    seed: u64,
+) -> MatchRecord {
+    let mut record = MatchRecord::new(first, second);
+    let needed = best_of / 2 + 1;
+
+    for n in 0..best_of as u64 {
+        let (light, dark) = if n % 2 == 0 { (first, second) } else { (second, first) };
+        let seed = game_seed(seed, n);
+        let (light_bot, dark_bot) = pair_mut(strategies, light, dark);
+        let (game, turns) = play_game(light_bot, dark_bot, seed);
+
+        record.add_game(GameRecord {
+            light,
+            dark,
+            winner: game.winner().map(|w| if w == Player::Light { light } else { dark }),
+            result: game.result().unwrap_or(GameResult::Abandoned),
+            turns,
+            seed,
+        });
+
+        if record.wins(first) >= needed || record.wins(second) >= needed {
+            break;
+        }
+    }
+
+    record
+}
+
+/// Round-robin schedule (circle method). Each round is a list of pairings;
+/// with an odd number of entrants one entrant sits out each round.
+pub fn round_robin(entrants: usize) -> Vec<Vec<(usize, usize)>> {
+    if entrants < 2 {
+        return Vec::new();
+    }
+
+    // Pad to an even count with a "bye" slot
+    let slots = entrants + entrants % 2;
+    let mut order: Vec<usize> = (0..slots).collect();
+    let mut rounds = Vec::new();
+
+    for _ in 0..slots - 1 {
+        let round = (0..slots / 2)
+            .map(|i| (order[i], order[slots - 1 - i]))
+            .filter(|&(a, b)| a < entrants && b < entrants)
+            .collect();
+        rounds.push(round);
+        // Keep the first slot fixed and rotate the rest
+        order[1..].rotate_right(1);
+    }
+
+    rounds
+}
+
+/// Swiss pairings: entrants ranked by `order` (best first) are paired with the
+/// nearest-ranked opponent they haven't met. Returns the pairings and the bye, if any.
+pub fn swiss_pairings(order: &[usize], played: &[(usize, usize)], had_bye: &[usize]) -> (Vec<(usize, usize)>, Option<usize>) {
+    let met = |a: usize, b: usize| played.iter().any(|&(x, y)| (x, y) == (a, b) || (x, y) == (b, a));
+    let mut unpaired: Vec<usize> = order.to_vec();
+
+    // Lowest-ranked entrant who hasn't had a bye sits out
+    let bye = if unpaired.len() % 2 == 1 {
+        let pos = unpaired
+            .iter()
+            .rposition(|e| !had_bye.contains(e))
+            .unwrap_or(unpaired.len() - 1);
+        Some(unpaired.remove(pos))
+    } else {
+        None
+    };
+
+    let mut pairings = Vec::new();
+    while !unpaired.is_empty() {
+        let a = unpaired.remove(0);
+        // Prefer a fresh opponent; fall back to a rematch rather than leaving someone out
+        let pos = unpaired.iter().position(|&b| !met(a, b)).unwrap_or(0);
+        let b = unpaired.remove(pos);
+        pairings.push((a, b));
+    }
+
+    (pairings, bye)
+}
+
+/// Glicko-1 rating
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct Glicko {
+    pub rating: f64,
+    pub deviation: f64,
+}
+
+impl Default for Glicko {
+    fn default() -> Self {
+        Glicko { rating: START_ELO, deviation: START_DEVIATION }
+    }
+}
+
+impl Glicko {
+    /// New rating after a rating period of `(opponent, score)` results (score 1, 0.5 or 0)
+    pub fn update(&self, results: &[(Glicko, f64)]) -> Glicko {
+        if results.is_empty() {
+            return *self;
+        }
+
+        let q = std::f64::consts::LN_10 / 400.0;
+        let g = |rd: f64| 1.0 / (1.0 + 3.0 * q * q * rd * rd / (std::f64::consts::PI * std::f64::consts::PI)).sqrt();
+        let expected = |opp: &Glicko| 1.0 / (1.0 + 10f64.powf(-g(opp.deviation) * (self.rating - opp.rating) / 400.0));
+
+        let d_inv: f64 = results
+            .iter()
+            .map(|(opp, _)| {
+                let e = expected(opp);
+                q * q * g(opp.deviation).powi(2) * e * (1.0 - e)
+            })
+            .sum();
+        let delta: f64 = results
+            .iter()
+            .map(|(opp, score)| g(opp.deviation) * (score - expected(opp)))
+            .sum();
+
+        let denominator = 1.0 / (self.deviation * self.deviation) + d_inv;
+        Glicko {
+            rating: self.rating + q / denominator * delta,
+            deviation: (1.0 / denominator).sqrt().max(MIN_DEVIATION),
+        }
+    }
+}
+
+/// Elo update for a single game. Returns the new ratings of `a` and `b`.
+pub fn elo_update(a: f64, b: f64, score_a: f64) -> (f64, f64) {
+    let expected_a = 1.0 / (1.0 + 10f64.powf((b - a) / 400.0));
+    let change = ELO_K * (score_a - expected_a);
+    (a + change, b - change)
+}
+
+/// How entrants are paired
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    RoundRobin,
+    Swiss { rounds: usize },
+}
+
+#[derive(Debug, Clone)]
+struct Entrant {
+    name: String,
+    elo: f64,
+    glicko: Glicko,
+    byes: u32,
+}
+
+/// One row of the standings table
+#[derive(Debug, Clone, Serialize)]
+pub struct Standing {
+    pub rank: usize,
+    pub entrant: usize,
+    pub name: String,
+    pub points: f64,
+    pub match_wins: u32,
+    pub match_draws: u32,
+    pub match_losses: u32,
+    pub game_wins: u32,
+    pub game_losses: u32,
+    pub byes: u32,
+    pub elo: f64,
+    pub glicko: Glicko,
+}
+
+/// A league event: schedules rounds, plays or records matches and keeps ratings
+#[derive(Debug, Clone)]
+pub struct Tournament {
+    entrants: Vec<Entrant>,
+    format: Format,
+    best_of: u32,
+    seed: u64,
+    matches: Vec<MatchRecord>,
+    round: usize,
+}
+
+impl Tournament {
+    pub fn new(names: Vec<String>, format: Format, best_of: u32, seed: u64) -> Tournament {
+        Tournament {
+            entrants: names
+                .into_iter()
+                .map(|name| Entrant { name, elo: START_ELO, glicko: Glicko::default(), byes: 0 })
+                .collect(),
+            format,
+            best_of: best_of.max(1),
+            seed,
+            matches: Vec::new(),
+            round: 0,
+        }
+    }
+
+    pub fn matches(&self) -> &[MatchRecord] {
+        &self.matches
+    }
+
+    /// Rounds played so far
+    pub fn round(&self) -> usize {
+        self.round
+    }
+
+    pub fn total_rounds(&self) -> usize {
+        match self.format {
+            Format::RoundRobin => round_robin(self.entrants.len()).len(),
+            Format::Swiss { rounds } => rounds,
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.round >= self.total_rounds()
+    }
+
+    /// Pairings (and bye) for the next round
+    pub fn next_pairings(&self) -> (Vec<(usize, usize)>, Option<usize>) {
+        match self.format {
+            Format::RoundRobin => {
+                let rounds = round_robin(self.entrants.len());
+                let pairings = rounds.get(self.round).cloned().unwrap_or_default();
+                let bye = (0..self.entrants.len())
+                    .find(|e| !pairings.iter().any(|&(a, b)| a == *e || b == *e))
+                    .filter(|_| !pairings.is_empty());
+                (pairings, bye)
+            }
+            Format::Swiss { .. } => {
+                let order: Vec<usize> = self.standings().iter().map(|s| s.entrant).collect();
+                let played: Vec<(usize, usize)> = self.matches.iter().map(|m| (m.first, m.second)).collect();
+                let had_bye: Vec<usize> = (0..self.entrants.len()).filter(|&e| self.entrants[e].byes > 0).collect();
+                swiss_pairings(&order, &played, &had_bye)
+            }
+        }
+    }
+
+    /// Play the next round with bots. `strategies[i]` plays for entrant `i`.
+    /// Returns false once every round has been played.
+    pub fn play_round(&mut self, strategies: &mut [Box<dyn Strategy>]) -> bool {
+        assert_eq!(strategies.len(), self.entrants.len(), "one strategy per entrant");
+        if self.is_finished() {
+            return false;
+        }
+
+        let (pairings, bye) = self.next_pairings();
+        for (n, &(first, second)) in pairings.iter().enumerate() {
+            let seed = game_seed(self.seed, ((self.round as u64) << 32) | n as u64);
+            let record = play_match(strategies, first, second, self.best_of, seed);
+            self.record_match(record);
+        }
+        if let Some(e) = bye {
+            self.entrants[e].byes += 1;
+        }
+
+        self.round += 1;
+        true
+    }
+
+    /// Play every remaining round
+    pub fn play(&mut self, strategies: &mut [Box<dyn Strategy>]) {
+        while self.play_round(strategies) {}
+    }
+
+    /// Record a match played outside the runner (e.g. between people) and update ratings
+    pub fn record_match(&mut self, record: MatchRecord) {
+        let (a, b) = (record.first, record.second);
+
+        // Glicko treats the match as one rating period, using pre-match ratings for both sides
+        let (glicko_a, glicko_b) = (self.entrants[a].glicko, self.entrants[b].glicko);
+        let mut results_a = Vec::new();
+        let mut results_b = Vec::new();
+
+        for game in &record.games {
+            let score_a = match game.winner {
+                Some(w) if w == a => 1.0,
+                Some(_) => 0.0,
+                None => 0.5,
+            };
+            let (elo_a, elo_b) = elo_update(self.entrants[a].elo, self.entrants[b].elo, score_a);
+            self.entrants[a].elo = elo_a;
+            self.entrants[b].elo = elo_b;
+            results_a.push((glicko_b, score_a));
+            results_b.push((glicko_a, 1.0 - score_a));
+        }
+
+        self.entrants[a].glicko = glicko_a.update(&results_a);
+        self.entrants[b].glicko = glicko_b.update(&results_b);
+        self.matches.push(record);
+    }
+
+    /// Standings, best first: points (match win 1, draw 0.5, bye 1), then game difference, then Elo
+    pub fn standings(&self) -> Vec<Standing> {
+        let mut rows: Vec<Standing> = self
+            .entrants
+            .iter()
+            .enumerate()
+            .map(|(i, e)| {
+                let mut row = Standing {
+                    rank: 0,
+                    entrant: i,
+                    name: e.name.clone(),
+                    points: e.byes as f64,
+                    match_wins: 0,
+                    match_draws: 0,
+                    match_losses: 0,
+                    game_wins: 0,
+                    game_losses: 0,
+                    byes: e.byes,
+                    elo: e.elo,
+                    glicko: e.glicko,
+                };
+                for m in self.matches.iter().filter(|m| m.first == i || m.second == i) {
+                    let opponent = if m.first == i { m.second } else { m.first };
+                    row.game_wins += m.wins(i);
+                    row.game_losses += m.wins(opponent);
+                    match m.winner() {
+                        Some(w) if w == i => {
+                            row.match_wins += 1;
+                            row.points += 1.0;
+                        }
+                        Some(_) => row.match_losses += 1,
+                        None => {
+                            row.match_draws += 1;
+                            row.points += 0.5;
+                        }
+                    }
+                }
+                row
+            })
+            .collect();
+
+        rows.sort_by(|a, b| {
+            b.points
+                .total_cmp(&a.points)
+                .then((b.game_wins as i64 - b.game_losses as i64).cmp(&(a.game_wins as i64 - a.game_losses as i64)))
+                .then(b.elo.total_cmp(&a.elo))
+                .then(a.entrant.cmp(&b.entrant))
+        });
+        for (i, row) in rows.iter_mut().enumerate() {
+            row.rank = i + 1;
+        }
+        rows
+    }
+
+    /// Every game played, one row per game, including how it ended
+    pub fn games_csv(&self) -> String {
+        let mut csv = String::from("match,game,light,dark,winner,result,turns,seed\n");
+        for (m, record) in self.matches.iter().enumerate() {
+            for (g, game) in record.games.iter().enumerate() {
+                csv.push_str(&format!(
+                    "{},{},{},{},{},{},{},{}\n",
+                    m + 1,
+                    g + 1,
+                    csv_field(&self.entrants[game.light].name),
+                    csv_field(&self.entrants[game.dark].name),
+                    game.winner.map_or(String::new(), |w| csv_field(&self.entrants[w].name)),
+                    game.result.label(),
+                    game.turns,
+                    game.seed,
+                ));
+            }
+        }
+        csv
+    }
+
+    /// Standings as JSON
+    pub fn standings_json(&self) -> String {
+        serde_json::to_string_pretty(&self.standings()).unwrap()
+    }
+
+    /// Standings as CSV
+    pub fn standings_csv(&self) -> String {
+        let mut csv = String::from(
+            "rank,name,points,match_wins,match_draws,match_losses,game_wins,game_losses,byes,elo,glicko,glicko_rd\n",
+        );
+        for s in self.standings() {
+            let name = csv_field(&s.name);
+            csv.push_str(&format!(
+                "{},{},{},{},{},{},{},{},{},{:.1},{:.1},{:.1}\n",
+                s.rank,
+                name,
+                s.points,
+                s.match_wins,
+                s.match_draws,
+                s.match_losses,
+                s.game_wins,
+                s.game_losses,
+                s.byes,
+                s.elo,
+                s.glicko.rating,
+                s.glicko.deviation,
+            ));
+        }
+        csv
+    }
+}
+
+/// Quote a CSV field if it needs it
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bots() -> Vec<Box<dyn Strategy>> {
+        vec![Box::new(EngineBot), Box::new(RunnerBot), Box::new(RandomBot::new(5))]
+    }
+
+    #[test]
+    fn seeded_tournaments_replay_exactly() {
+        let run = || {
+            let names = vec!["Engine".to_string(), "Runner".to_string(), "Random".to_string()];
+            let mut tournament = Tournament::new(names, Format::RoundRobin, 3, 2024);
+            tournament.play(&mut bots());
+            assert!(tournament.is_finished());
+            (tournament.games_csv(), tournament.standings_csv())
+        };
+        assert_eq!(run(), run());
+    }
+
+    #[test]
+    fn round_robin_pairs_everyone_once() {
+        let rounds = round_robin(5);
+        assert_eq!(rounds.len(), 5);
+        let mut pairs: Vec<(usize, usize)> = rounds.concat().into_iter().map(|(a, b)| (a.min(b), a.max(b))).collect();
+        pairs.sort_unstable();
+        pairs.dedup();
+        assert_eq!(pairs.len(), 10);
+    }
+}